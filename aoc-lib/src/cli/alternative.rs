@@ -0,0 +1,76 @@
+use super::{
+    batch::summarize,
+    util::{self, BenchResult},
+    Answer, SolutionResult,
+};
+use colored::Colorize;
+
+/// Another implementation of a puzzle part, e.g. a brute force kept around to check a faster
+/// solution. The runner runs it after the part and flags it if the answers disagree.
+pub struct Alternative<I, O> {
+    name: &'static str,
+    part: u32,
+    solve: fn(&I) -> SolutionResult<O>,
+}
+
+impl<I, O> Alternative<I, O> {
+    pub const fn new(name: &'static str, part: u32, solve: fn(&I) -> SolutionResult<O>) -> Self {
+        Self { name, part, solve }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn part(&self) -> u32 {
+        self.part
+    }
+
+    pub fn solve(&self, input: &I) -> SolutionResult<O> {
+        (self.solve)(input)
+    }
+}
+
+/// Runs the alternatives of a part and lists their answers, checked against `expected`, the
+/// answer of the part itself.
+pub(super) fn run_alternatives<I, O: Into<Answer>>(
+    alternatives: &[Alternative<I, O>],
+    input: &I,
+    part: u32,
+    expected: &SolutionResult<Answer>,
+) {
+    let alternatives = alternatives
+        .iter()
+        .filter(|alternative| alternative.part() == part)
+        .collect::<Vec<_>>();
+
+    if alternatives.is_empty() {
+        return;
+    }
+
+    println!("{}", "Alternatives:".bright_black());
+    let width = alternatives
+        .iter()
+        .map(|a| a.name().len())
+        .max()
+        .unwrap_or(0);
+
+    for alternative in alternatives {
+        let BenchResult(result, elapsed) = util::bench(|| alternative.solve(input).map(Into::into));
+        let name = format!("  {:<width$}", alternative.name(), width = width);
+
+        let text = match &result {
+            Ok(answer) => format!("{}  {}  {:.2?}", name, summarize(answer), elapsed),
+            Err(e) => format!("{}  error: {}", name, e),
+        };
+
+        let line = match (&result, expected) {
+            (Err(_), _) => text.red(),
+            (Ok(answer), Ok(expected)) if answer != expected => (text + "  disagrees").red(),
+            (Ok(_), Ok(_)) => text.green(),
+            (Ok(_), Err(_)) => text.normal(),
+        };
+
+        println!("{}", line);
+    }
+}
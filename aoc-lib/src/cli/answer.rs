@@ -0,0 +1,152 @@
+use serde::{
+    de::{self, MapAccess, Visitor},
+    ser::SerializeMap,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use std::fmt::{self, Display};
+
+/// The answer to a puzzle part.
+///
+/// Every part's output is converted into an `Answer` before being reported, so that results of
+/// different types can be compared, stored and serialized alike.
+///
+/// In JSON, integers and text map to numbers and strings, while art is written as `{"art": ...}`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+    /// Multi-line output, such as pixels that have to be read by eye.
+    Art(String),
+}
+
+impl Answer {
+    pub fn art<S: Into<String>>(art: S) -> Self {
+        Self::Art(art.into())
+    }
+
+    pub fn is_multiline(&self) -> bool {
+        match self {
+            Self::Integer(_) => false,
+            Self::Text(s) | Self::Art(s) => s.contains('\n'),
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Integer(n) => n.fmt(f),
+            Self::Text(s) | Self::Art(s) => s.fmt(f),
+        }
+    }
+}
+
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Integer(n) => serializer.serialize_i128(*n),
+            Self::Text(s) => serializer.serialize_str(s),
+            Self::Art(art) => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry("art", art)?;
+                map.end()
+            }
+        }
+    }
+}
+
+struct AnswerVisitor;
+
+impl<'de> Visitor<'de> for AnswerVisitor {
+    type Value = Answer;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "an integer, a string or an art object")
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        Ok(Answer::from(v))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        Ok(Answer::from(v))
+    }
+
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<Self::Value, E> {
+        Ok(Answer::from(v))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(Answer::from(v))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        match map.next_entry::<String, String>()? {
+            Some((key, art)) if key == "art" => Ok(Answer::Art(art)),
+            Some((key, _)) => Err(de::Error::unknown_field(&key, &["art"])),
+            None => Err(de::Error::missing_field("art")),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Answer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(AnswerVisitor)
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Self::Integer(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, i128);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integers_compare_across_types() {
+        assert_eq!(Answer::from(42_u8), Answer::from(42_i64));
+        assert_eq!(Answer::from(u64::MAX), Answer::Integer(u64::MAX as i128));
+        assert_ne!(Answer::from(42_usize), Answer::from("42"));
+    }
+
+    #[test]
+    fn json_round_trip_works() {
+        let answers = vec![
+            Answer::from(-7_i32),
+            Answer::from("4,6,3,5,6,3,5,2,1,0"),
+            Answer::art("#..#\n####"),
+        ];
+
+        let json = serde_json::to_string(&answers).unwrap();
+        assert_eq!(
+            json,
+            r###"[-7,"4,6,3,5,6,3,5,2,1,0",{"art":"#..#\n####"}]"###
+        );
+
+        let parsed: Vec<Answer> = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, answers);
+    }
+}
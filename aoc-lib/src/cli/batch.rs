@@ -0,0 +1,380 @@
+use super::{memory, Answer, MemoryStats, ParsedInput, Solution, SolutionResult};
+use colored::{ColoredString, Colorize};
+use serde::Deserialize;
+use std::{
+    any::Any,
+    error::Error,
+    fs,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+/// The answers expected for an input, read from a `<name>.answers.json` file next to it, e.g.
+/// `{"part_1": 41, "part_2": "6,1"}`.
+#[derive(Debug, Clone, Default, Deserialize)]
+struct ExpectedAnswers {
+    part_1: Option<Answer>,
+    part_2: Option<Answer>,
+}
+
+impl ExpectedAnswers {
+    fn read(input_path: &Path) -> Result<Self, Box<dyn Error>> {
+        let path = input_path.with_extension("answers.json");
+
+        if !path.try_exists()? {
+            return Ok(Self::default());
+        }
+
+        let contents = fs::read_to_string(&path)?;
+        serde_json::from_str(&contents)
+            .map_err(|e| format!("invalid answers file {}: {}", path.display(), e).into())
+    }
+
+    fn part(&self, part: u32) -> Option<&Answer> {
+        match part {
+            1 => self.part_1.as_ref(),
+            _ => self.part_2.as_ref(),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Outcome {
+    Correct(Answer),
+    Wrong { expected: Answer, actual: Answer },
+    Unchecked(Answer),
+    Failed(String),
+}
+
+#[derive(Debug)]
+struct CellResult {
+    outcome: Outcome,
+    elapsed: Duration,
+    memory: Option<MemoryStats>,
+}
+
+impl CellResult {
+    fn text(&self) -> String {
+        let (symbol, answer) = match &self.outcome {
+            Outcome::Correct(answer) => ("✓", summarize(answer)),
+            Outcome::Wrong { actual, .. } => ("✗", summarize(actual)),
+            Outcome::Unchecked(answer) => ("?", summarize(answer)),
+            Outcome::Failed(_) => ("!", "failed".to_string()),
+        };
+
+        format!("{} {}  {:.2?}", symbol, answer, self.elapsed)
+    }
+
+    fn paint(&self, text: String) -> ColoredString {
+        match self.outcome {
+            Outcome::Correct(_) => text.green(),
+            Outcome::Wrong { .. } | Outcome::Failed(_) => text.red(),
+            Outcome::Unchecked(_) => text.normal(),
+        }
+    }
+}
+
+pub(super) fn summarize(answer: &Answer) -> String {
+    if answer.is_multiline() {
+        "[multi-line]".to_string()
+    } else {
+        answer.to_string()
+    }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        format!("panicked: {}", message)
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        format!("panicked: {}", message)
+    } else {
+        "panicked".to_string()
+    }
+}
+
+/// Runs `f`, turning a panic into an error so that one input can't stop the whole batch.
+pub(super) fn catch<T, F: FnOnce() -> SolutionResult<T>>(f: F) -> Result<T, String> {
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(result) => result.map_err(|e| e.to_string()),
+        Err(payload) => Err(panic_message(payload)),
+    }
+}
+
+/// Runs `f` without the default panic hook printing the panics it catches.
+pub(super) fn quietly<T, F: FnOnce() -> T>(f: F) -> T {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let result = f();
+
+    panic::set_hook(hook);
+    result
+}
+
+fn run_cell(
+    solution: &Solution,
+    input: &ParsedInput,
+    part: u32,
+    expected: Option<&Answer>,
+) -> CellResult {
+    let owned = solution.clone_input(input);
+
+    let start = Instant::now();
+    let (result, memory) = memory::measure(|| {
+        catch(|| match owned {
+            Some(owned) => solution.into_part(owned, part),
+            None => solution.part(input, part),
+        })
+    });
+    let elapsed = start.elapsed();
+
+    // Answers are compared by how they're printed, so that e.g. an integer in the answers file
+    // matches a part that outputs text.
+    let outcome = match (result, expected) {
+        (Err(e), _) => Outcome::Failed(e),
+        (Ok(actual), Some(expected)) if actual.to_string() == expected.to_string() => {
+            Outcome::Correct(actual)
+        }
+        (Ok(actual), Some(expected)) => Outcome::Wrong {
+            expected: expected.clone(),
+            actual,
+        },
+        (Ok(actual), None) => Outcome::Unchecked(actual),
+    };
+
+    CellResult {
+        outcome,
+        elapsed,
+        memory,
+    }
+}
+
+struct Row {
+    name: String,
+    /// Set if the input couldn't be parsed, in which case no part was run.
+    parse_error: Option<String>,
+    shared: Option<Result<Duration, String>>,
+    cells: Vec<CellResult>,
+}
+
+fn run_input(solution: &Solution, path: &Path, parts: &[u32]) -> Result<Row, Box<dyn Error>> {
+    let name = path
+        .file_stem()
+        .unwrap_or_default()
+        .to_string_lossy()
+        .into_owned();
+    let expected = ExpectedAnswers::read(path)?;
+    let raw_input = fs::read_to_string(path)?;
+
+    let input = match catch(|| Ok(solution.parse(raw_input))) {
+        Ok(input) => input,
+        Err(e) => {
+            return Ok(Row {
+                name,
+                parse_error: Some(e),
+                shared: None,
+                cells: Vec::new(),
+            })
+        }
+    };
+
+    let shared = solution.is_shared().then(|| {
+        let start = Instant::now();
+        catch(|| solution.prepare(&input)).map(|_| start.elapsed())
+    });
+
+    let cells = parts
+        .iter()
+        .map(|&part| run_cell(solution, &input, part, expected.part(part)))
+        .collect();
+
+    Ok(Row {
+        name,
+        parse_error: None,
+        shared,
+        cells,
+    })
+}
+
+fn input_paths(dir: &Path) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    let mut paths = Vec::new();
+
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+
+        if path.is_file() && path.extension().is_some_and(|ext| ext == "txt") {
+            paths.push(path);
+        }
+    }
+
+    paths.sort();
+    Ok(paths)
+}
+
+/// The heap usage of the parts of a row together, if memory is tracked.
+fn row_memory(row: &Row) -> Option<MemoryStats> {
+    row.cells
+        .iter()
+        .map(|cell| cell.memory)
+        .reduce(|a, b| Some(a?.then(b?)))
+        .flatten()
+}
+
+fn print_matrix(rows: &[Row], parts: &[u32], shared: bool) {
+    // Memory is either tracked for every cell or for none of them.
+    let memory = rows.iter().any(|row| row_memory(row).is_some());
+
+    let mut header = vec!["Input".to_string()];
+    if shared {
+        header.push("Shared".to_string());
+    }
+    header.extend(parts.iter().map(|part| format!("Part {}", part)));
+    if memory {
+        header.push("Memory".to_string());
+    }
+
+    let texts = rows
+        .iter()
+        .map(|row| {
+            let mut texts = vec![row.name.clone()];
+
+            if row.parse_error.is_some() {
+                texts.resize(header.len(), "! parsing failed".to_string());
+                return texts;
+            }
+
+            if shared {
+                texts.push(match &row.shared {
+                    Some(Ok(elapsed)) => format!("{:.2?}", elapsed),
+                    _ => "failed".to_string(),
+                });
+            }
+            texts.extend(row.cells.iter().map(CellResult::text));
+            if memory {
+                texts.push(row_memory(row).map_or(String::new(), |m| m.to_string()));
+            }
+            texts
+        })
+        .collect::<Vec<_>>();
+
+    let widths = (0..header.len())
+        .map(|k| {
+            texts
+                .iter()
+                .map(|row| row[k].chars().count())
+                .chain([header[k].len()])
+                .max()
+                .unwrap_or(0)
+        })
+        .collect::<Vec<_>>();
+
+    let line = header
+        .iter()
+        .zip(&widths)
+        .map(|(title, &width)| format!("{:<width$}", title, width = width))
+        .collect::<Vec<_>>()
+        .join("  ");
+    println!("{}", line.trim_end().bold());
+
+    let first_cell = header.len() - parts.len() - usize::from(memory);
+
+    for (row, texts) in rows.iter().zip(texts) {
+        let line = texts
+            .into_iter()
+            .zip(&widths)
+            .enumerate()
+            .map(|(k, (text, &width))| {
+                let padding = " ".repeat(width - text.chars().count());
+
+                let text = if k > 0 && row.parse_error.is_some() {
+                    text.red().to_string()
+                } else if (first_cell..first_cell + parts.len()).contains(&k) {
+                    row.cells[k - first_cell].paint(text).to_string()
+                } else if k == 1 && shared && !matches!(row.shared, Some(Ok(_))) {
+                    text.red().to_string()
+                } else {
+                    text
+                };
+
+                text + &padding
+            })
+            .collect::<Vec<_>>()
+            .join("  ");
+
+        println!("{}", line.trim_end());
+    }
+}
+
+fn print_problems(rows: &[Row], parts: &[u32]) {
+    for row in rows {
+        if let Some(e) = &row.parse_error {
+            println!("{} {} parsing: {}", "Error:".red(), row.name, e);
+        }
+
+        if let Some(Err(e)) = &row.shared {
+            println!("{} {} shared: {}", "Error:".red(), row.name, e);
+        }
+
+        for (cell, part) in row.cells.iter().zip(parts) {
+            match &cell.outcome {
+                Outcome::Wrong { expected, actual } => println!(
+                    "{} {} part {}: expected {}, got {}",
+                    "Wrong:".red(),
+                    row.name,
+                    part,
+                    expected,
+                    actual
+                ),
+                Outcome::Failed(e) => {
+                    println!("{} {} part {}: {}", "Error:".red(), row.name, part, e)
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Runs a solution against every `.txt` input in a directory, checking the answers of those
+/// that have an answers file, and prints the results as a matrix of inputs and parts.
+pub(super) fn run_inputs_dir(
+    solution: &Solution,
+    dir: &Path,
+    parts: &[u32],
+) -> Result<(), Box<dyn Error>> {
+    let paths = input_paths(dir)?;
+
+    if paths.is_empty() {
+        return Err(format!("there are no .txt inputs in {}", dir.display()).into());
+    }
+
+    // Panics are reported in the matrix, so the default hook would only clutter the output.
+    let rows = quietly(|| {
+        paths
+            .iter()
+            .map(|path| run_input(solution, path, parts))
+            .collect::<Result<Vec<_>, _>>()
+    })?;
+
+    print_matrix(&rows, parts, solution.is_shared());
+    print_problems(&rows, parts);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expected_answers_parse() {
+        let expected: ExpectedAnswers =
+            serde_json::from_str(r#"{"part_1": 41, "part_2": "6,1"}"#).unwrap();
+
+        assert_eq!(expected.part(1), Some(&Answer::from(41)));
+        assert_eq!(expected.part(2), Some(&Answer::from("6,1")));
+
+        let expected: ExpectedAnswers = serde_json::from_str(r#"{"part_2": 6}"#).unwrap();
+        assert_eq!(expected.part(1), None);
+    }
+}
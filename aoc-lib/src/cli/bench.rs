@@ -0,0 +1,137 @@
+use super::{input, memory, MemoryStats, PuzzleDate, Registry};
+use colored::Colorize;
+use std::{
+    env,
+    time::{Duration, Instant},
+};
+
+const TARGET_TIME: Duration = Duration::from_secs(1);
+const MAX_RUNS: u32 = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Timings {
+    min: Duration,
+    total: Duration,
+    runs: u32,
+    /// The heap usage of a single run, if memory is tracked.
+    memory: Option<MemoryStats>,
+}
+
+impl Timings {
+    fn mean(&self) -> Duration {
+        self.total / self.runs
+    }
+}
+
+/// Runs `f` repeatedly until it has taken about [`TARGET_TIME`] or run [`MAX_RUNS`] times. The
+/// value it takes is built by `setup` before each run, which isn't timed. A first, untimed run
+/// records the heap usage.
+fn measure<I, T, S, F>(mut setup: S, mut f: F) -> Timings
+where
+    S: FnMut() -> I,
+    F: FnMut(I) -> T,
+{
+    let value = setup();
+    let (result, memory) = memory::measure(|| f(value));
+    drop(result);
+
+    let mut timings = Timings {
+        min: Duration::MAX,
+        total: Duration::ZERO,
+        runs: 0,
+        memory,
+    };
+
+    while timings.runs < MAX_RUNS && timings.total < TARGET_TIME {
+        let value = setup();
+        let start = Instant::now();
+        let result = f(value);
+        let elapsed = start.elapsed();
+        drop(result);
+
+        timings.min = timings.min.min(elapsed);
+        timings.total += elapsed;
+        timings.runs += 1;
+    }
+
+    timings
+}
+
+fn print_timings(label: &str, timings: &Timings) {
+    let memory = match timings.memory {
+        Some(memory) => format!(", {}", memory),
+        None => String::new(),
+    };
+
+    println!(
+        "  {:<16} {:>12.2?} {}",
+        label,
+        timings.mean(),
+        format!("(min {:.2?}, {} runs{})", timings.min, timings.runs, memory).bright_black()
+    );
+}
+
+/// Benchmarks parsing, shared steps and both parts of every registered day against its cached
+/// input, with the alternatives of each part listed under it. Meant to be called from a
+/// `harness = false` bench target, so `cargo bench -- 6 7` only runs days 6 and 7.
+pub fn bench(registry: &Registry) {
+    let days = env::args()
+        .skip(1)
+        .filter_map(|arg| arg.parse::<u32>().ok())
+        .collect::<Vec<_>>();
+
+    for solution in registry.solutions() {
+        if !days.is_empty() && !days.contains(&solution.day()) {
+            continue;
+        }
+
+        println!("{}", format!("Day {}", solution.day()).bold().bright_blue());
+
+        let date = PuzzleDate::new(registry.year(), solution.day());
+        let raw_input = match input::read_cached_input(&date) {
+            Ok(Some(raw_input)) => raw_input,
+            _ => {
+                println!("{}", "  skipped, input is not cached".yellow());
+                continue;
+            }
+        };
+
+        let timings = measure(|| raw_input.clone(), |raw_input| solution.parse(raw_input));
+        print_timings("parse", &timings);
+
+        if solution.is_shared() {
+            // The input is returned so that it is dropped outside of the timed section.
+            let timings = measure(
+                || solution.parse(raw_input.clone()),
+                |input| {
+                    let result = solution.prepare(&input);
+                    (input, result)
+                },
+            );
+            print_timings("shared", &timings);
+        }
+
+        let input = solution.parse(raw_input);
+
+        for part in [1, 2] {
+            if solution.part(&input, part).is_err() {
+                continue;
+            }
+
+            // Parts that take ownership of the input get a copy made outside of the timed section.
+            let timings = match solution.is_owned() {
+                true => measure(
+                    || solution.clone_input(&input).unwrap(),
+                    |owned| solution.into_part(owned, part),
+                ),
+                false => measure(|| (), |_| solution.part(&input, part)),
+            };
+            print_timings(&format!("part {}", part), &timings);
+
+            for name in solution.alternatives(part) {
+                let timings = measure(|| (), |_| solution.alternative(&input, part, name));
+                print_timings(&format!("  {}", name), &timings);
+            }
+        }
+    }
+}
@@ -0,0 +1,312 @@
+use super::{
+    batch::{catch, quietly},
+    with_params, Answer, Solution,
+};
+use colored::Colorize;
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    ops::Range,
+};
+
+pub use fastrand::Rng;
+
+/// How many times a disagreement is shrunk at most, in case shrinking doesn't make progress.
+const MAX_SHRINK_STEPS: usize = 1000;
+
+/// A randomly generated raw input, along with the parameters it's meant to be solved with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Generated {
+    pub input: String,
+    pub params: Vec<(&'static str, String)>,
+}
+
+impl Generated {
+    pub fn new<S: Into<String>>(input: S) -> Self {
+        Self {
+            input: input.into(),
+            params: Vec::new(),
+        }
+    }
+
+    pub fn with_param<T: ToString>(mut self, name: &'static str, value: T) -> Self {
+        self.params.push((name, value.to_string()));
+        self
+    }
+
+    /// Returns every variation of the input with one of its lines removed.
+    pub fn without_each_line(&self) -> Vec<Self> {
+        let lines = self.input.lines().collect::<Vec<_>>();
+
+        (0..lines.len())
+            .map(|skipped| Self {
+                input: lines
+                    .iter()
+                    .enumerate()
+                    .filter(|&(i, _)| i != skipped)
+                    .map(|(_, line)| format!("{}\n", line))
+                    .collect(),
+                params: self.params.clone(),
+            })
+            .collect()
+    }
+}
+
+impl Display for Generated {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (name, value) in &self.params {
+            writeln!(f, "--param {}={}", name, value)?;
+        }
+
+        write!(f, "{}", self.input)
+    }
+}
+
+/// A solution that can generate random valid inputs, on which its parts are checked against their
+/// alternatives to catch the edge cases that real inputs never hit. It is registered with one of
+/// the `Solution::new_*generated` constructors, whichever matches its kind of solution.
+pub trait InputGenerator {
+    /// Generates an input, which should only depend on the numbers drawn from `rng`.
+    fn generate(rng: &mut Rng) -> Generated;
+
+    /// Returns simpler variations of an input, which are tried in order when shrinking a
+    /// disagreement. By default, these are the input with one of its lines removed.
+    fn shrink(generated: &Generated) -> Vec<Generated> {
+        generated.without_each_line()
+    }
+}
+
+/// The functions of solutions that can generate inputs.
+#[derive(Clone, Copy)]
+pub(super) struct GeneratorFns {
+    pub(super) generate: fn(&mut Rng) -> Generated,
+    pub(super) shrink: fn(&Generated) -> Vec<Generated>,
+}
+
+impl GeneratorFns {
+    pub(super) fn of<S: InputGenerator>() -> Self {
+        Self {
+            generate: S::generate,
+            shrink: S::shrink,
+        }
+    }
+}
+
+/// An alternative that disagrees with its part on a generated input.
+#[derive(Debug)]
+pub struct Mismatch {
+    /// The seed the input was originally generated from.
+    pub seed: u64,
+    pub part: u32,
+    pub alternative: &'static str,
+    /// The simplest input found on which the disagreement still shows.
+    pub input: Generated,
+    pub expected: Result<Answer, String>,
+    pub actual: Result<Answer, String>,
+}
+
+fn describe(result: &Result<Answer, String>) -> String {
+    match result {
+        Ok(answer) => answer.to_string(),
+        Err(e) => format!("a failure ({})", e),
+    }
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "part {} gives {}, but `{}` gives {}, on an input shrunk from seed {}:",
+            self.part,
+            describe(&self.expected),
+            self.alternative,
+            describe(&self.actual),
+            self.seed
+        )?;
+        write!(f, "{}", self.input)
+    }
+}
+
+type Results = (Result<Answer, String>, Result<Answer, String>);
+
+/// Solves a part of a generated input both with the part itself and one of its alternatives,
+/// returning their results if they disagree. Two failures count as agreeing, and so do inputs that
+/// can't be parsed.
+fn compare(solution: &Solution, generated: &Generated, part: u32, name: &str) -> Option<Results> {
+    let params = generated
+        .params
+        .iter()
+        .map(|(name, value)| (*name, value.as_str()))
+        .collect::<Vec<_>>();
+
+    with_params(&params, || {
+        let input = catch(|| Ok(solution.parse(generated.input.clone()))).ok()?;
+
+        let expected = catch(|| solution.part(&input, part));
+        let actual = catch(|| {
+            solution
+                .alternative(&input, part, name)
+                .expect("alternative is registered")
+        });
+
+        let agree = match (&expected, &actual) {
+            (Ok(expected), Ok(actual)) => expected == actual,
+            (Err(_), Err(_)) => true,
+            _ => false,
+        };
+
+        (!agree).then_some((expected, actual))
+    })
+}
+
+fn shrink(solution: &Solution, generator: GeneratorFns, mut mismatch: Mismatch) -> Mismatch {
+    for _ in 0..MAX_SHRINK_STEPS {
+        let smaller = (generator.shrink)(&mismatch.input)
+            .into_iter()
+            .find_map(|candidate| {
+                compare(solution, &candidate, mismatch.part, mismatch.alternative)
+                    .map(|results| (candidate, results))
+            });
+
+        match smaller {
+            Some((input, (expected, actual))) => {
+                mismatch = Mismatch {
+                    input,
+                    expected,
+                    actual,
+                    ..mismatch
+                }
+            }
+            None => break,
+        }
+    }
+
+    mismatch
+}
+
+/// Checks the alternatives of both parts against the parts themselves on an input generated from
+/// each seed. Returns the first disagreement found, shrunk to the simplest input that still shows
+/// it.
+///
+/// # Panics
+///
+/// Panics if the solution has no input generator.
+pub fn cross_check(solution: &Solution, seeds: Range<u64>) -> Option<Mismatch> {
+    let generator = solution
+        .generator()
+        .expect("solution has no input generator");
+
+    quietly(|| {
+        for seed in seeds {
+            let generated = (generator.generate)(&mut Rng::with_seed(seed));
+
+            for part in [1, 2] {
+                for alternative in solution.alternatives(part) {
+                    if let Some((expected, actual)) =
+                        compare(solution, &generated, part, alternative)
+                    {
+                        let mismatch = Mismatch {
+                            seed,
+                            part,
+                            alternative,
+                            input: generated,
+                            expected,
+                            actual,
+                        };
+
+                        return Some(shrink(solution, generator, mismatch));
+                    }
+                }
+            }
+        }
+
+        None
+    })
+}
+
+/// Cross-checks a solution on `count` generated inputs and reports the outcome.
+pub(super) fn run_cross_check(solution: &Solution, count: u64) -> Result<(), Box<dyn Error>> {
+    if solution.generator().is_none() {
+        return Err("this day has no input generator".into());
+    }
+
+    if solution
+        .alternatives(1)
+        .chain(solution.alternatives(2))
+        .next()
+        .is_none()
+    {
+        return Err("this day has no alternatives to check".into());
+    }
+
+    match cross_check(solution, 0..count) {
+        None => println!(
+            "{}",
+            format!("The alternatives agree on {} generated inputs", count).green()
+        ),
+        Some(mismatch) => print!("{} {}", "Mismatch:".red(), mismatch),
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::{Alternative, PuzzleSolution, SolutionResult};
+
+    /// Sums numbers, with an alternative that gets it wrong whenever a number is above 5.
+    struct Sum;
+
+    impl PuzzleSolution for Sum {
+        type Input = Vec<u32>;
+        type Output = u32;
+
+        fn parse_input(raw_input: String) -> Self::Input {
+            raw_input.lines().map(|n| n.parse().unwrap()).collect()
+        }
+
+        fn part_1(input: &Self::Input) -> SolutionResult<Self::Output> {
+            Ok(input.iter().sum())
+        }
+
+        fn alternatives() -> Vec<Alternative<Self::Input, Self::Output>> {
+            vec![Alternative::new("capped", 1, |input| {
+                Ok(input.iter().map(|&n| n.min(5)).sum())
+            })]
+        }
+    }
+
+    impl InputGenerator for Sum {
+        fn generate(rng: &mut Rng) -> Generated {
+            let numbers = (0..10)
+                .map(|_| format!("{}\n", rng.u32(0..10)))
+                .collect::<String>();
+
+            Generated::new(numbers)
+        }
+    }
+
+    #[test]
+    fn mismatches_are_shrunk() {
+        let solution = Solution::new_generated::<Sum>(1);
+        let mismatch = cross_check(&solution, 0..10).unwrap();
+
+        assert_eq!(mismatch.input.input.lines().count(), 1);
+        assert!(mismatch.input.input.trim().parse::<u32>().unwrap() > 5);
+        assert_eq!(mismatch.alternative, "capped");
+    }
+
+    #[test]
+    fn lines_are_removed() {
+        let generated = Generated::new("1\n2\n3\n").with_param("size", 3);
+        let inputs = generated
+            .without_each_line()
+            .into_iter()
+            .map(|generated| generated.input)
+            .collect::<Vec<_>>();
+
+        assert_eq!(inputs, ["2\n3\n", "1\n3\n", "1\n2\n"]);
+        assert_eq!(generated.to_string(), "--param size=3\n1\n2\n3\n");
+    }
+}
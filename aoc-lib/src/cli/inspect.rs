@@ -0,0 +1,236 @@
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+};
+
+/// How many characters of the histogram are shown per line.
+const CHARS_PER_LINE: usize = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct IntegerStats {
+    count: usize,
+    negatives: usize,
+    min: i128,
+    max: i128,
+}
+
+/// A summary of the structure of a raw input, to help choosing how to parse it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct InputReport {
+    lines: usize,
+    line_lengths: Option<(usize, usize)>,
+    /// The characters of the input other than line breaks, most frequent first.
+    chars: Vec<(char, usize)>,
+    /// The number of rows and columns, if every line has the same length.
+    grid: Option<(usize, usize)>,
+    /// The number of lines of each section, where sections are separated by blank lines.
+    sections: Vec<usize>,
+    integers: Option<IntegerStats>,
+    crlf_lines: usize,
+    trailing_whitespace_lines: usize,
+}
+
+/// Finds the integers in a line, counting a `-` right before digits as a sign unless it follows
+/// a digit, as in a range like `3-5`.
+fn integers(line: &str) -> impl Iterator<Item = i128> + '_ {
+    let bytes = line.as_bytes();
+    let mut i = 0;
+
+    std::iter::from_fn(move || {
+        while i < bytes.len() {
+            if !bytes[i].is_ascii_digit() {
+                i += 1;
+                continue;
+            }
+
+            let start = i;
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+
+            let negative = start > 0
+                && bytes[start - 1] == b'-'
+                && (start < 2 || !bytes[start - 2].is_ascii_digit());
+            let start = if negative { start - 1 } else { start };
+
+            // Numbers too large for an `i128` are skipped.
+            if let Ok(n) = line[start..i].parse() {
+                return Some(n);
+            }
+        }
+
+        None
+    })
+}
+
+impl InputReport {
+    pub(super) fn new(raw_input: &str) -> Self {
+        let lines = raw_input.lines().collect::<Vec<_>>();
+
+        let mut counts = HashMap::new();
+        for c in raw_input.chars().filter(|&c| c != '\n' && c != '\r') {
+            *counts.entry(c).or_insert(0) += 1;
+        }
+        let mut chars = counts.into_iter().collect::<Vec<_>>();
+        chars.sort_unstable_by_key(|&(c, count)| (usize::MAX - count, c));
+
+        let mut sections = Vec::new();
+        for group in lines.split(|line| line.trim().is_empty()) {
+            if !group.is_empty() {
+                sections.push(group.len());
+            }
+        }
+
+        let lengths = lines.iter().map(|line| line.chars().count());
+        let line_lengths = lengths.clone().min().zip(lengths.max());
+
+        let grid = match line_lengths {
+            Some((min, max)) if min == max && min > 0 && lines.len() > 1 => {
+                Some((lines.len(), min))
+            }
+            _ => None,
+        };
+
+        let integers = lines.iter().flat_map(|line| integers(line)).fold(
+            None,
+            |stats: Option<IntegerStats>, n| {
+                let mut stats = stats.unwrap_or(IntegerStats {
+                    count: 0,
+                    negatives: 0,
+                    min: n,
+                    max: n,
+                });
+
+                stats.count += 1;
+                stats.negatives += usize::from(n < 0);
+                stats.min = stats.min.min(n);
+                stats.max = stats.max.max(n);
+                Some(stats)
+            },
+        );
+
+        Self {
+            lines: lines.len(),
+            line_lengths,
+            chars,
+            grid,
+            sections,
+            integers,
+            crlf_lines: raw_input.matches("\r\n").count(),
+            trailing_whitespace_lines: lines
+                .iter()
+                .filter(|line| line.ends_with(char::is_whitespace))
+                .count(),
+        }
+    }
+
+    /// Returns the problems with the input that could trip up parsing.
+    pub(super) fn warnings(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+
+        if self.crlf_lines > 0 {
+            warnings.push(format!("{} lines end with CRLF", self.crlf_lines));
+        }
+
+        if self.trailing_whitespace_lines > 0 {
+            warnings.push(format!(
+                "{} lines have trailing whitespace",
+                self.trailing_whitespace_lines
+            ));
+        }
+
+        warnings
+    }
+}
+
+fn escape(c: char) -> String {
+    match c {
+        ' ' => "space".to_string(),
+        '\t' => "tab".to_string(),
+        c => format!("'{}'", c),
+    }
+}
+
+impl Display for InputReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line_lengths {
+            Some((min, max)) if min == max => {
+                writeln!(f, "Lines:      {}, {} characters long", self.lines, min)?
+            }
+            Some((min, max)) => writeln!(
+                f,
+                "Lines:      {}, {} to {} characters long",
+                self.lines, min, max
+            )?,
+            None => writeln!(f, "Lines:      none")?,
+        }
+
+        for (i, chunk) in self.chars.chunks(CHARS_PER_LINE).enumerate() {
+            let chunk = chunk
+                .iter()
+                .map(|&(c, count)| format!("{} ×{}", escape(c), count))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let label = if i == 0 { "Characters:" } else { "" };
+
+            writeln!(f, "{:<11} {}", label, chunk)?;
+        }
+
+        if let Some((rows, columns)) = self.grid {
+            writeln!(f, "Grid:       {} rows × {} columns", rows, columns)?;
+        }
+
+        if self.sections.len() > 1 {
+            let sizes = self
+                .sections
+                .iter()
+                .map(usize::to_string)
+                .collect::<Vec<_>>()
+                .join(", ");
+            writeln!(f, "Sections:   {}, of {} lines", self.sections.len(), sizes)?;
+        }
+
+        match self.integers {
+            Some(stats) => writeln!(
+                f,
+                "Integers:   {}, from {} to {}, {} negative",
+                stats.count, stats.min, stats.max, stats.negatives
+            ),
+            None => writeln!(f, "Integers:   none"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integers_are_found() {
+        let found = integers("p=0,4 v=3,-3 range 10-12 x-7").collect::<Vec<_>>();
+        assert_eq!(found, [0, 4, 3, -3, 10, 12, -7]);
+    }
+
+    #[test]
+    fn grids_and_sections_are_detected() {
+        let report = InputReport::new("#..\n.#.\n..#\n");
+        assert_eq!(report.grid, Some((3, 3)));
+        assert_eq!(report.chars, [('.', 6), ('#', 3)]);
+        assert!(report.warnings().is_empty());
+
+        let report = InputReport::new("1|2 \r\n\r\n3,4\r\n5,-6\r\n");
+        assert_eq!(report.grid, None);
+        assert_eq!(report.sections, [1, 2]);
+        assert_eq!(report.crlf_lines, 4);
+        assert_eq!(report.trailing_whitespace_lines, 1);
+        assert_eq!(
+            report.integers,
+            Some(IntegerStats {
+                count: 6,
+                negatives: 1,
+                min: -6,
+                max: 5,
+            })
+        );
+    }
+}
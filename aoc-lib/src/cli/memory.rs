@@ -0,0 +1,229 @@
+use serde::Serialize;
+use std::fmt::Display;
+
+/// Heap usage recorded while running a phase of a solution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+pub struct MemoryStats {
+    /// Total bytes requested from the allocator, including memory that was later freed.
+    pub allocated: u64,
+    pub allocations: u64,
+    /// Highest number of live bytes at any point, relative to the start of the phase.
+    pub peak: u64,
+}
+
+impl MemoryStats {
+    /// Combines the usage of two phases that ran one after the other: allocations add up, and the
+    /// peak is the higher of the two.
+    pub fn then(self, other: Self) -> Self {
+        Self {
+            allocated: self.allocated + other.allocated,
+            allocations: self.allocations + other.allocations,
+            peak: self.peak.max(other.peak),
+        }
+    }
+}
+
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{:.1} {}", value, UNITS[unit])
+}
+
+impl Display for MemoryStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} allocated in {} allocations, {} peak",
+            format_bytes(self.allocated),
+            self.allocations,
+            format_bytes(self.peak)
+        )
+    }
+}
+
+#[cfg(feature = "alloc-stats")]
+mod counting {
+    use super::MemoryStats;
+    use std::{
+        alloc::{GlobalAlloc, Layout, System},
+        cell::Cell,
+        sync::atomic::{AtomicU64, Ordering},
+    };
+
+    static ALLOCATED: AtomicU64 = AtomicU64::new(0);
+    static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+    static LIVE: AtomicU64 = AtomicU64::new(0);
+    static PEAK: AtomicU64 = AtomicU64::new(0);
+
+    thread_local! {
+        // Const-initialized without a destructor, so reading it never allocates.
+        static IGNORED: Cell<bool> = const { Cell::new(false) };
+    }
+
+    pub fn ignore_current_thread() {
+        IGNORED.set(true);
+    }
+
+    struct CountingAllocator;
+
+    impl CountingAllocator {
+        fn is_ignored() -> bool {
+            IGNORED.try_with(Cell::get).unwrap_or(false)
+        }
+
+        fn record_alloc(size: u64) {
+            if Self::is_ignored() {
+                return;
+            }
+
+            ALLOCATED.fetch_add(size, Ordering::Relaxed);
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+            let live = LIVE.fetch_add(size, Ordering::Relaxed) + size;
+            PEAK.fetch_max(live, Ordering::Relaxed);
+        }
+
+        fn record_dealloc(size: u64) {
+            if Self::is_ignored() {
+                return;
+            }
+
+            LIVE.fetch_sub(size, Ordering::Relaxed);
+        }
+    }
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc(layout);
+            if !ptr.is_null() {
+                Self::record_alloc(layout.size() as u64);
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc_zeroed(layout);
+            if !ptr.is_null() {
+                Self::record_alloc(layout.size() as u64);
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+            Self::record_dealloc(layout.size() as u64);
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_ptr = System.realloc(ptr, layout, new_size);
+            if !new_ptr.is_null() {
+                Self::record_dealloc(layout.size() as u64);
+                Self::record_alloc(new_size as u64);
+            }
+            new_ptr
+        }
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    pub fn measure<T, F: FnOnce() -> T>(f: F) -> (T, Option<MemoryStats>) {
+        let allocated = ALLOCATED.load(Ordering::Relaxed);
+        let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+        let live = LIVE.load(Ordering::Relaxed);
+        PEAK.store(live, Ordering::Relaxed);
+
+        let result = f();
+
+        let stats = MemoryStats {
+            allocated: ALLOCATED.load(Ordering::Relaxed) - allocated,
+            allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+            peak: PEAK.load(Ordering::Relaxed).saturating_sub(live),
+        };
+
+        (result, Some(stats))
+    }
+}
+
+/// Runs `f`, returning the heap usage it caused. Memory is only tracked when `aoc-lib` is built
+/// with the `alloc-stats` feature, which installs a counting global allocator; otherwise `None`
+/// is returned.
+pub fn measure<T, F: FnOnce() -> T>(f: F) -> (T, Option<MemoryStats>) {
+    #[cfg(feature = "alloc-stats")]
+    {
+        counting::measure(f)
+    }
+
+    #[cfg(not(feature = "alloc-stats"))]
+    {
+        (f(), None)
+    }
+}
+
+/// Leaves the allocations of the current thread out of every measurement, for helper threads
+/// such as the progress bar that run alongside a solution. Memory must be freed on the thread
+/// that allocated it for the figures to stay right.
+pub(crate) fn ignore_current_thread() {
+    #[cfg(feature = "alloc-stats")]
+    counting::ignore_current_thread();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn memory_stats_display_works() {
+        let stats = MemoryStats {
+            allocated: 3 * 1024 * 1024 + 512 * 1024,
+            allocations: 42,
+            peak: 900,
+        };
+
+        assert_eq!(
+            stats.to_string(),
+            "3.5 MiB allocated in 42 allocations, 900 B peak"
+        );
+    }
+
+    #[cfg(feature = "alloc-stats")]
+    #[test]
+    fn measure_counts_allocations() {
+        let (_, stats) = measure(|| {
+            let v = vec![0u8; 4096];
+            drop(v);
+            vec![0u64; 16]
+        });
+        let stats = stats.unwrap();
+
+        assert!(stats.allocated >= 4096 + 128);
+        assert!(stats.allocations >= 2);
+        assert!(stats.peak >= 4096);
+    }
+
+    #[cfg(feature = "alloc-stats")]
+    #[test]
+    fn ignored_threads_are_not_counted() {
+        let (_, stats) = measure(|| {
+            std::thread::scope(|scope| {
+                scope.spawn(|| {
+                    ignore_current_thread();
+                    drop(std::hint::black_box(vec![0u8; 1 << 20]));
+                });
+            })
+        });
+
+        assert!(stats.unwrap().allocated < 1 << 20);
+    }
+}
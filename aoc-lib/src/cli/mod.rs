@@ -1,11 +1,36 @@
+use crate::visual::{self, Animation, ExportOptions, Palette};
 use clap::Parser;
 use colored::Colorize;
-use std::{env, fmt::Display, fs};
+use inspect::InputReport;
+use progress::ProgressBar;
+use spans::SpanTree;
+use std::{env, error::Error, fmt::Display, fs, path::PathBuf, time::Duration};
 use util::BenchResult;
 
+mod alternative;
+mod answer;
+mod batch;
+mod bench;
+mod generate;
 mod input;
+mod inspect;
+mod memory;
+mod params;
+mod progress;
+mod registry;
+mod spans;
 mod util;
 
+pub use alternative::Alternative;
+pub use answer::Answer;
+pub use bench::bench;
+pub use generate::{cross_check, Generated, InputGenerator, Mismatch, Rng};
+pub use memory::MemoryStats;
+pub use params::{set_param, with_params, Param, ParamInfo};
+pub use progress::Progress;
+pub use registry::{ParsedInput, Registry, Solution};
+pub use spans::{span, Span, SpanNode};
+
 pub type SolutionResult<T> = Result<T, SolutionError>;
 
 #[derive(Debug)]
@@ -29,7 +54,7 @@ impl Display for SolutionError {
 
 pub trait PuzzleSolution {
     type Input;
-    type Output: Display;
+    type Output: Into<Answer>;
 
     fn parse_input(raw_input: String) -> Self::Input;
 
@@ -41,8 +66,86 @@ pub trait PuzzleSolution {
         Err(SolutionError::Unimplemented)
     }
 
-    fn visualize(_input: &Self::Input) {
-        unimplemented!("unimplemented visualization");
+    /// Other implementations of the parts, which are checked against them and benchmarked
+    /// alongside them.
+    fn alternatives() -> Vec<Alternative<Self::Input, Self::Output>> {
+        Vec::new()
+    }
+
+    /// The parameters the solution reads, which `--param` may override.
+    fn params() -> Vec<ParamInfo> {
+        Vec::new()
+    }
+
+    fn visualize(_input: &Self::Input, _animation: &mut Animation) -> SolutionResult<()> {
+        Err(SolutionError::Unimplemented)
+    }
+}
+
+/// A solution whose parts take ownership of the input, e.g. to simulate on it in place. Each part
+/// gets its own copy, cloned before the part starts being timed.
+pub trait OwnedSolution {
+    type Input: Clone;
+    type Output: Into<Answer>;
+
+    fn parse_input(raw_input: String) -> Self::Input;
+
+    fn part_1(_input: Self::Input) -> SolutionResult<Self::Output> {
+        Err(SolutionError::Unimplemented)
+    }
+
+    fn part_2(_input: Self::Input) -> SolutionResult<Self::Output> {
+        Err(SolutionError::Unimplemented)
+    }
+
+    /// Other implementations of the parts, which are checked against them and benchmarked
+    /// alongside them. They borrow the input, as they run after the parts.
+    fn alternatives() -> Vec<Alternative<Self::Input, Self::Output>> {
+        Vec::new()
+    }
+
+    /// The parameters the solution reads, which `--param` may override.
+    fn params() -> Vec<ParamInfo> {
+        Vec::new()
+    }
+
+    fn visualize(_input: &Self::Input, _animation: &mut Animation) -> SolutionResult<()> {
+        Err(SolutionError::Unimplemented)
+    }
+}
+
+/// A solution whose parts are derived from a shared intermediate result, such as a distance map,
+/// which `solve` computes once from the input.
+pub trait SharedSolution {
+    type Input;
+    type Shared;
+    type Output: Into<Answer>;
+
+    fn parse_input(raw_input: String) -> Self::Input;
+
+    fn solve(input: &Self::Input) -> SolutionResult<Self::Shared>;
+
+    fn part_1(_input: &Self::Input, _shared: &Self::Shared) -> SolutionResult<Self::Output> {
+        Err(SolutionError::Unimplemented)
+    }
+
+    fn part_2(_input: &Self::Input, _shared: &Self::Shared) -> SolutionResult<Self::Output> {
+        Err(SolutionError::Unimplemented)
+    }
+
+    /// Other implementations of the parts, which are checked against them and benchmarked
+    /// alongside them. They only get the input, so that they don't depend on `solve`.
+    fn alternatives() -> Vec<Alternative<Self::Input, Self::Output>> {
+        Vec::new()
+    }
+
+    /// The parameters the solution reads, which `--param` may override.
+    fn params() -> Vec<ParamInfo> {
+        Vec::new()
+    }
+
+    fn visualize(_input: &Self::Input, _animation: &mut Animation) -> SolutionResult<()> {
+        Err(SolutionError::Unimplemented)
     }
 }
 
@@ -60,64 +163,147 @@ impl PuzzleDate {
 
 #[derive(Parser, Debug)]
 struct Args {
+    /// The day to run, optionally preceded by the year. Every day is run if omitted
+    #[arg(value_name = "[YEAR] DAY", num_args = 0..=2)]
+    date: Vec<u32>,
+
     #[arg(short, long)]
     input: Option<String>,
 
+    /// Run against every .txt input in a directory, checking them against `<name>.answers.json`
+    /// files where present
+    #[arg(long, value_name = "DIR", conflicts_with = "input")]
+    inputs_dir: Option<PathBuf>,
+
+    /// Check the alternatives of each part against it on this many generated inputs
+    #[arg(long, value_name = "COUNT", conflicts_with_all = ["input", "inputs_dir"])]
+    cross_check: Option<u64>,
+
     #[arg(short, long)]
     part: Option<u32>,
 
+    /// Print a summary of the structure of the input instead of solving it
+    #[arg(long, conflicts_with_all = ["inputs_dir", "cross_check"])]
+    inspect: bool,
+
     #[arg(short, long)]
     visualize: bool,
 
+    /// Export the visualization to a .gif, .cast or numbered .png files instead of playing it
+    #[arg(short, long, value_name = "PATH")]
+    export: Option<PathBuf>,
+
+    /// Size in pixels of each cell in exported images
+    #[arg(long, default_value_t = 4)]
+    cell_size: u32,
+
+    /// Color palette for exported images (dark or light)
+    #[arg(long, default_value = "dark", value_parser = parse_palette)]
+    palette: Palette,
+
+    /// Frames per second of exported animations
+    #[arg(long, default_value_t = 10)]
+    fps: u32,
+
+    /// Override a puzzle parameter, e.g. `--param width=11`
+    #[arg(long = "param", value_name = "NAME=VALUE", value_parser = params::parse_param)]
+    params: Vec<(String, String)>,
+
+    /// Show the timings of the spans recorded by each part
+    #[arg(long)]
+    spans: bool,
+
     #[arg(short, long, default_value = "AOC_SESSION_ID")]
     session_cookie_var: String,
 }
 
-fn run_part<F, I, T: Display>(f: F, input: &I, part: u32)
-where
-    F: FnOnce(&I) -> SolutionResult<T>,
-{
+fn parse_palette(s: &str) -> Result<Palette, String> {
+    Palette::by_name(s).ok_or_else(|| format!("unknown palette `{}`", s))
+}
+
+fn print_title(date: &PuzzleDate) {
     println!(
         "{}",
-        format!("{:=^32}", format!(" Part {} ", part))
+        format!("Advent of Code {}, day {}", date.year, date.day)
             .bold()
-            .yellow()
+            .bright_blue()
     );
+}
+
+fn print_header(title: &str) {
+    println!(
+        "{}",
+        format!("{:=^32}", format!(" {} ", title)).bold().yellow()
+    );
+}
+
+/// Runs a timed phase of a solution, calling `report` with its result before printing the time
+/// and memory it took.
+fn run_phase<T, F, R>(f: F, report: R, show_spans: bool) -> (T, Duration)
+where
+    F: FnOnce() -> T,
+    R: FnOnce(&T),
+{
+    spans::reset();
+    let progress_bar = ProgressBar::spawn();
+    let (BenchResult(result, elapsed), memory) = memory::measure(|| util::bench(f));
+    progress_bar.finish();
+
+    report(&result);
+
+    match memory {
+        Some(memory) => println!("{}", format!("{:.2?} elapsed, {}", elapsed, memory).white()),
+        None => println!("{}", format!("{:.2?} elapsed", elapsed).white()),
+    }
 
-    println!("{}", "Running...".bright_black());
+    let spans = spans::take();
 
-    let BenchResult(result, elapsed) = util::bench(|| f(input));
+    if show_spans && !spans.is_empty() {
+        println!("{}", "Spans:".bright_black());
+        print!("{}", SpanTree(&spans).to_string().bright_black());
+    }
 
-    util::goto_previous_line();
+    (result, elapsed)
+}
 
-    match result {
+fn run_part<F, T: Into<Answer>>(
+    f: F,
+    part: u32,
+    show_spans: bool,
+) -> (SolutionResult<Answer>, Duration)
+where
+    F: FnOnce() -> SolutionResult<T>,
+{
+    print_header(&format!("Part {}", part));
+
+    let report = |result: &SolutionResult<Answer>| match result {
+        Ok(answer) if answer.is_multiline() => println!("{}\n{}", "Result:".bright_green(), answer),
         Ok(answer) => println!("{} {}", "Result:".bright_green(), answer),
         Err(e) => println!("{} {}", "Error:".red(), e),
-    }
+    };
 
-    println!("{}", format!("{:.2?} elapsed", elapsed).white());
+    run_phase(|| f().map(Into::into), report, show_spans)
 }
 
-fn run_solution_unwrapped<S: PuzzleSolution>(
-    args: Args,
-    date: PuzzleDate,
-) -> Result<(), Box<dyn std::error::Error>> {
-    println!(
-        "{}",
-        format!("Advent of Code {}, day {}", date.year, date.day)
-            .bold()
-            .bright_blue()
-    );
+fn selected_parts(args: &Args) -> Result<&'static [u32], Box<dyn Error>> {
+    match args.part {
+        None => Ok(&[1, 2]),
+        Some(1) => Ok(&[1]),
+        Some(2) => Ok(&[2]),
+        Some(_) => Err("invalid part selection".into()),
+    }
+}
 
-    let raw_input = match args.input {
+fn read_input(args: &Args, date: &PuzzleDate) -> Result<String, Box<dyn Error>> {
+    let raw_input = match &args.input {
         Some(filename) => fs::read_to_string(filename)?,
-        None => match input::read_cached_input(&date)? {
+        None => match input::read_cached_input(date)? {
             Some(input) => input,
             None => {
                 println!("{}", "Fetching input...".bright_black());
 
-                let input = input::fetch_input(&date, &env::var(args.session_cookie_var)?)?;
-                let filename = input::input_cache_path(&date);
+                let input = input::fetch_input(date, &env::var(&args.session_cookie_var)?)?;
+                let filename = input::input_cache_path(date);
                 util::write_dir_safe(filename, &input)?;
 
                 util::goto_previous_line();
@@ -126,32 +312,289 @@ fn run_solution_unwrapped<S: PuzzleSolution>(
         },
     };
 
-    let input = S::parse_input(raw_input);
+    Ok(raw_input)
+}
 
-    if args.visualize {
-        S::visualize(&input);
-    } else {
-        match args.part {
-            None => {
-                run_part(S::part_1, &input, 1);
-                run_part(S::part_2, &input, 2);
+/// Reads the input of a puzzle and parses it, after applying the parameter overrides.
+fn prepare_input<I, F: FnOnce(String) -> I>(
+    args: &Args,
+    date: &PuzzleDate,
+    parse: F,
+) -> Result<I, Box<dyn Error>> {
+    print_title(date);
+
+    let raw_input = read_input(args, date)?;
+
+    for (name, value) in &args.params {
+        set_param(name, value);
+    }
+
+    let (input, memory) = memory::measure(|| parse(raw_input));
+
+    if let Some(memory) = memory {
+        println!("{}", format!("Parsing: {}", memory).white());
+    }
+
+    Ok(input)
+}
+
+fn wants_animation(args: &Args) -> bool {
+    args.visualize || args.export.is_some()
+}
+
+/// Plays an animation, or exports it if a path was given.
+fn show_animation(args: &Args, animation: &Animation) -> Result<(), Box<dyn Error>> {
+    match &args.export {
+        Some(path) => {
+            let options = ExportOptions {
+                cell_size: args.cell_size,
+                palette: args.palette.clone(),
+                fps: args.fps,
+            };
+
+            visual::export(animation, &options, path)?;
+            println!(
+                "{}",
+                format!("Exported {} frames to {}", animation.len(), path.display()).white()
+            );
+        }
+        None => visual::play(animation)?,
+    }
+
+    Ok(())
+}
+
+/// Runs a solution as selected by the arguments, returning the total time spent in its parts.
+fn run_solution_unwrapped<S: PuzzleSolution>(
+    args: &Args,
+    date: PuzzleDate,
+) -> Result<Duration, Box<dyn Error>> {
+    let input = prepare_input(args, &date, S::parse_input)?;
+
+    if wants_animation(args) {
+        let mut animation = Animation::new();
+        S::visualize(&input, &mut animation)?;
+        show_animation(args, &animation)?;
+        return Ok(Duration::ZERO);
+    }
+
+    let alternatives = S::alternatives();
+    let mut elapsed = Duration::ZERO;
+
+    for &part in selected_parts(args)? {
+        let (result, part_elapsed) = match part {
+            1 => run_part(|| S::part_1(&input), 1, args.spans),
+            _ => run_part(|| S::part_2(&input), 2, args.spans),
+        };
+
+        alternative::run_alternatives(&alternatives, &input, part, &result);
+        elapsed += part_elapsed;
+    }
+
+    Ok(elapsed)
+}
+
+/// Runs a shared solution as selected by the arguments, returning the total time spent in its
+/// shared step and its parts.
+fn run_shared_solution_unwrapped<S: SharedSolution>(
+    args: &Args,
+    date: PuzzleDate,
+) -> Result<Duration, Box<dyn Error>> {
+    let input = prepare_input(args, &date, S::parse_input)?;
+
+    if wants_animation(args) {
+        let mut animation = Animation::new();
+        S::visualize(&input, &mut animation)?;
+        show_animation(args, &animation)?;
+        return Ok(Duration::ZERO);
+    }
+
+    let parts = selected_parts(args)?;
+    let alternatives = S::alternatives();
+
+    print_header("Shared");
+    let (shared, mut elapsed) = run_phase(|| S::solve(&input), |_| {}, args.spans);
+    let shared = shared?;
+
+    for &part in parts {
+        let (result, part_elapsed) = match part {
+            1 => run_part(|| S::part_1(&input, &shared), 1, args.spans),
+            _ => run_part(|| S::part_2(&input, &shared), 2, args.spans),
+        };
+
+        alternative::run_alternatives(&alternatives, &input, part, &result);
+        elapsed += part_elapsed;
+    }
+
+    Ok(elapsed)
+}
+
+/// Runs an owned solution as selected by the arguments, returning the total time spent in its
+/// parts.
+fn run_owned_solution_unwrapped<S: OwnedSolution>(
+    args: &Args,
+    date: PuzzleDate,
+) -> Result<Duration, Box<dyn Error>> {
+    let input = prepare_input(args, &date, S::parse_input)?;
+
+    if wants_animation(args) {
+        let mut animation = Animation::new();
+        S::visualize(&input, &mut animation)?;
+        show_animation(args, &animation)?;
+        return Ok(Duration::ZERO);
+    }
+
+    let parts = selected_parts(args)?;
+    let alternatives = S::alternatives();
+    let mut input = Some(input);
+    let mut elapsed = Duration::ZERO;
+
+    for (k, &part) in parts.iter().enumerate() {
+        // The last part can take the parsed input itself, unless its alternatives still need it.
+        let needed = alternatives.iter().any(|a| a.part() == part);
+        let owned = if k + 1 == parts.len() && !needed {
+            input.take()
+        } else {
+            input.clone()
+        }
+        .expect("input is only taken by the last part");
+
+        let (result, part_elapsed) = match part {
+            1 => run_part(|| S::part_1(owned), 1, args.spans),
+            _ => run_part(|| S::part_2(owned), 2, args.spans),
+        };
+
+        if let Some(input) = &input {
+            alternative::run_alternatives(&alternatives, input, part, &result);
+        }
+        elapsed += part_elapsed;
+    }
+
+    Ok(elapsed)
+}
+
+/// Runs every registered day one after another, then prints how long each of them took.
+fn run_year(registry: &Registry, args: &Args) -> Result<(), Box<dyn Error>> {
+    if args.input.is_some()
+        || args.inputs_dir.is_some()
+        || args.cross_check.is_some()
+        || args.inspect
+        || args.visualize
+        || args.export.is_some()
+        || !args.params.is_empty()
+    {
+        return Err(
+            "--input, --inputs-dir, --cross-check, --inspect, --visualize, --export and --param \
+             need a single day to be selected"
+                .into(),
+        );
+    }
+
+    let mut times = Vec::new();
+
+    for solution in registry.solutions() {
+        let date = PuzzleDate::new(registry.year(), solution.day());
+
+        match (solution.run_fn())(args, date) {
+            Ok(elapsed) => times.push((solution.day(), Some(elapsed))),
+            Err(e) => {
+                println!("{} {}", "Error:".red(), e);
+                times.push((solution.day(), None));
             }
-            Some(1) => run_part(S::part_1, &input, 1),
-            Some(2) => run_part(S::part_2, &input, 2),
-            Some(_) => return Err("invalid part selection".into()),
+        }
+
+        println!();
+    }
+
+    println!("{}", format!("{:=^32}", " Summary ").bold().yellow());
+
+    for (day, elapsed) in &times {
+        match elapsed {
+            Some(elapsed) => println!("Day {:>2}  {:>12.2?}", day, elapsed),
+            None => println!("Day {:>2}  {:>12}", day, "failed".red()),
         }
     }
 
+    let total = times
+        .iter()
+        .filter_map(|(_, elapsed)| *elapsed)
+        .sum::<Duration>();
+    println!("{}", format!("Total   {:>12.2?}", total).bold());
+
     Ok(())
 }
 
-pub fn run_solution<S: PuzzleSolution>(year: u32, day: u32) {
-    let args = Args::parse();
-    let date = PuzzleDate::new(year, day);
+fn run_unwrapped(registry: &Registry, args: &Args) -> Result<(), Box<dyn Error>> {
+    if args.spans && !spans::ENABLED {
+        println!(
+            "{}",
+            "Spans are disabled, rebuild with `--features aoc-lib/spans` to record them".yellow()
+        );
+    }
+
+    let day = match args.date[..] {
+        [] => return run_year(registry, args),
+        [day] => day,
+        [year, day] if year == registry.year() => day,
+        [year, _] => return Err(format!("there are no solutions for {}", year).into()),
+        _ => unreachable!("clap accepts at most two date arguments"),
+    };
+
+    let solution = registry
+        .get(day)
+        .ok_or_else(|| format!("there is no solution for day {}", day))?;
+
+    let date = PuzzleDate::new(registry.year(), day);
+
+    params::check_params(solution.params(), &args.params)?;
 
-    let result = run_solution_unwrapped::<S>(args, date);
+    if let Some(dir) = &args.inputs_dir {
+        print_title(&date);
 
-    if let Err(e) = result {
+        for (name, value) in &args.params {
+            set_param(name, value);
+        }
+
+        return batch::run_inputs_dir(solution, dir, selected_parts(args)?);
+    }
+
+    if args.inspect {
+        print_title(&date);
+
+        let report = InputReport::new(&read_input(args, &date)?);
+        print!("{}", report.to_string().white());
+
+        for warning in report.warnings() {
+            println!("{} {}", "Warning:".yellow(), warning);
+        }
+
+        return Ok(());
+    }
+
+    if let Some(count) = args.cross_check {
+        print_title(&date);
+        return generate::run_cross_check(solution, count);
+    }
+
+    (solution.run_fn())(args, date)?;
+    Ok(())
+}
+
+/// Runs the solutions of a registry from the command line, e.g. `aoc 6` or `aoc 2024 6` for a
+/// single day, or `aoc` for all of them.
+pub fn run(registry: Registry) {
+    let args = Args::parse();
+
+    if let Err(e) = run_unwrapped(&registry, &args) {
         println!("{} {}", "Error:".red(), e);
     }
 }
+
+/// Runs a single solution from the command line.
+pub fn run_solution<S: PuzzleSolution>(year: u32, day: u32)
+where
+    S::Input: 'static,
+    S::Output: 'static,
+{
+    run(Registry::new(year).with::<S>(day));
+}
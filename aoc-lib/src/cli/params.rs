@@ -0,0 +1,224 @@
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    fmt::{Debug, Display},
+    str::FromStr,
+};
+
+thread_local! {
+    static OVERRIDES: RefCell<HashMap<String, String>> = RefCell::new(HashMap::new());
+}
+
+/// A named puzzle parameter, such as a grid size, that differs between the examples and the real
+/// input. Its default is the value for the real input, which can be overridden from the command
+/// line with `--param name=value` or for a single run with [`with_params`].
+///
+/// Overrides are kept per thread, so a part that spawns worker threads should read its
+/// parameters before spawning them and hand the values over, rather than calling
+/// [`Param::get`] from the workers.
+///
+/// Solutions declare their parameters with [`Param::info`], so that the command line can reject
+/// unknown names and invalid values before solving.
+#[derive(Debug, Clone, Copy)]
+pub struct Param<T> {
+    name: &'static str,
+    default: T,
+}
+
+impl<T> Param<T> {
+    pub const fn new(name: &'static str, default: T) -> Self {
+        Self { name, default }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+impl<T: FromStr + Clone> Param<T>
+where
+    T::Err: Debug,
+{
+    /// Returns the overridden value of the parameter, or its default if there is none.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the overridden value can't be parsed as a `T`.
+    pub fn get(&self) -> T {
+        OVERRIDES.with_borrow(|overrides| match overrides.get(self.name) {
+            Some(value) => value
+                .parse()
+                .unwrap_or_else(|e| panic!("invalid value for parameter `{}`: {:?}", self.name, e)),
+            None => self.default.clone(),
+        })
+    }
+}
+
+/// A parameter as declared by a solution, to check overrides against.
+#[derive(Debug, Clone, Copy)]
+pub struct ParamInfo {
+    name: &'static str,
+    check: fn(&str) -> Result<(), String>,
+}
+
+impl ParamInfo {
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+fn check_value<T: FromStr>(value: &str) -> Result<(), String>
+where
+    T::Err: Display,
+{
+    value.parse::<T>().map(|_| ()).map_err(|e| e.to_string())
+}
+
+impl<T: FromStr> Param<T>
+where
+    T::Err: Display,
+{
+    pub fn info(&self) -> ParamInfo {
+        ParamInfo {
+            name: self.name,
+            check: check_value::<T>,
+        }
+    }
+}
+
+/// Checks overrides against the parameters a solution declares, rejecting unknown names and
+/// values that don't parse.
+pub(crate) fn check_params(
+    declared: &[ParamInfo],
+    overrides: &[(String, String)],
+) -> Result<(), String> {
+    for (name, value) in overrides {
+        let Some(info) = declared.iter().find(|info| info.name == name) else {
+            return Err(match declared {
+                [] => format!("unknown parameter `{}`, this day has none", name),
+                _ => format!(
+                    "unknown parameter `{}`, expected one of {}",
+                    name,
+                    declared
+                        .iter()
+                        .map(|info| format!("`{}`", info.name))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            });
+        };
+
+        (info.check)(value)
+            .map_err(|e| format!("invalid value `{}` for parameter `{}`: {}", value, name, e))?;
+    }
+
+    Ok(())
+}
+
+/// Overrides a parameter for the rest of the run on the current thread.
+pub fn set_param(name: &str, value: &str) {
+    OVERRIDES.with_borrow_mut(|overrides| overrides.insert(name.to_owned(), value.to_owned()));
+}
+
+/// Puts back the overrides that were in place before [`with_params`], even if its closure
+/// panics.
+struct RestoreOverrides(HashMap<String, String>);
+
+impl Drop for RestoreOverrides {
+    fn drop(&mut self) {
+        OVERRIDES.set(std::mem::take(&mut self.0));
+    }
+}
+
+/// Runs `f` with the given parameters overridden on the current thread, restoring the previous
+/// values afterwards.
+pub fn with_params<T, F: FnOnce() -> T>(params: &[(&str, &str)], f: F) -> T {
+    let _restore = OVERRIDES.with_borrow_mut(|overrides| {
+        let previous = overrides.clone();
+        for (name, value) in params {
+            overrides.insert(name.to_string(), value.to_string());
+        }
+        RestoreOverrides(previous)
+    });
+
+    f()
+}
+
+/// Parses a `name=value` pair, as given to `--param`.
+pub(crate) fn parse_param(s: &str) -> Result<(String, String), String> {
+    match s.split_once('=') {
+        Some((name, value)) if !name.is_empty() => Ok((name.to_owned(), value.to_owned())),
+        _ => Err(format!("expected `name=value`, got `{}`", s)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GRID_SIZE: Param<usize> = Param::new("grid_size", 71);
+    const LABEL: Param<String> = Param::new("label", String::new());
+
+    #[test]
+    fn overrides_are_scoped() {
+        assert_eq!(GRID_SIZE.get(), 71);
+
+        with_params(&[("grid_size", "7"), ("label", "example")], || {
+            assert_eq!(GRID_SIZE.get(), 7);
+            assert_eq!(LABEL.get(), "example");
+
+            with_params(&[("grid_size", "3")], || assert_eq!(GRID_SIZE.get(), 3));
+            assert_eq!(GRID_SIZE.get(), 7);
+        });
+
+        assert_eq!(GRID_SIZE.get(), 71);
+        assert_eq!(LABEL.get(), "");
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid value for parameter `grid_size`")]
+    fn invalid_override_panics() {
+        with_params(&[("grid_size", "big")], || GRID_SIZE.get());
+    }
+
+    #[test]
+    fn overrides_are_restored_after_a_panic() {
+        let result = std::panic::catch_unwind(|| {
+            with_params(&[("grid_size", "7")], || panic!("solution failed"))
+        });
+
+        assert!(result.is_err());
+        assert_eq!(GRID_SIZE.get(), 71);
+    }
+
+    #[test]
+    fn overrides_are_checked() {
+        let declared = [GRID_SIZE.info(), LABEL.info()];
+        let param = |name: &str, value: &str| vec![(name.to_owned(), value.to_owned())];
+
+        assert!(check_params(&declared, &param("grid_size", "7")).is_ok());
+        assert!(check_params(&declared, &param("label", "anything")).is_ok());
+        assert_eq!(
+            check_params(&declared, &param("gird_size", "7")),
+            Err("unknown parameter `gird_size`, expected one of `grid_size`, `label`".to_owned())
+        );
+        assert_eq!(
+            check_params(&declared, &param("grid_size", "big")),
+            Err(
+                "invalid value `big` for parameter `grid_size`: invalid digit found in string"
+                    .to_owned()
+            )
+        );
+        assert!(check_params(&[], &param("grid_size", "7")).is_err());
+    }
+
+    #[test]
+    fn param_parsing_works() {
+        assert_eq!(
+            parse_param("width=11"),
+            Ok(("width".to_owned(), "11".to_owned()))
+        );
+        assert!(parse_param("width").is_err());
+        assert!(parse_param("=11").is_err());
+    }
+}
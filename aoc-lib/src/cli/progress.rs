@@ -0,0 +1,173 @@
+use super::memory;
+use colored::Colorize;
+use std::{
+    io::{self, IsTerminal, Write},
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+const SPINNER: [char; 10] = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+const REFRESH_INTERVAL: Duration = Duration::from_millis(80);
+const BAR_WIDTH: usize = 24;
+
+struct State {
+    current: AtomicU64,
+    /// Zero when the total is unknown.
+    total: AtomicU64,
+    stage: Mutex<Option<String>>,
+}
+
+static STATE: State = State {
+    current: AtomicU64::new(0),
+    total: AtomicU64::new(0),
+    stage: Mutex::new(None),
+};
+
+/// A handle for reporting the progress of a long-running part, which the runner shows as a live
+/// progress bar. Updating the counter is a single relaxed atomic operation, so it can be called
+/// from hot loops; the display is refreshed on a separate thread.
+#[derive(Debug, Clone, Copy)]
+pub struct Progress {
+    _private: (),
+}
+
+impl Progress {
+    /// Starts reporting progress, resetting the counter and stage.
+    pub fn new(total: Option<u64>) -> Self {
+        STATE.current.store(0, Ordering::Relaxed);
+        STATE.total.store(total.unwrap_or(0), Ordering::Relaxed);
+        *STATE.stage.lock().unwrap() = None;
+        Self { _private: () }
+    }
+
+    pub fn inc(&self) {
+        STATE.current.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn add(&self, n: u64) {
+        STATE.current.fetch_add(n, Ordering::Relaxed);
+    }
+
+    pub fn set(&self, n: u64) {
+        STATE.current.store(n, Ordering::Relaxed);
+    }
+
+    pub fn set_total(&self, total: u64) {
+        STATE.total.store(total, Ordering::Relaxed);
+    }
+
+    pub fn set_stage<S: Into<String>>(&self, stage: S) {
+        *STATE.stage.lock().unwrap() = Some(stage.into());
+    }
+
+    pub fn current(&self) -> u64 {
+        STATE.current.load(Ordering::Relaxed)
+    }
+}
+
+fn format_line(tick: usize, elapsed: Duration) -> String {
+    let current = STATE.current.load(Ordering::Relaxed);
+    let total = STATE.total.load(Ordering::Relaxed);
+    let stage = STATE.stage.lock().unwrap().clone();
+
+    let mut line = format!(
+        "{} {}",
+        SPINNER[tick % SPINNER.len()],
+        stage.as_deref().unwrap_or("Running...")
+    );
+
+    if total > 0 {
+        let ratio = (current as f64 / total as f64).min(1.0);
+        let filled = (ratio * BAR_WIDTH as f64) as usize;
+
+        line += &format!(
+            " [{}{}] {:>3.0}% {}/{}",
+            "#".repeat(filled),
+            "-".repeat(BAR_WIDTH - filled),
+            ratio * 100.0,
+            current,
+            total
+        );
+
+        if current > 0 && current < total {
+            let eta = elapsed.mul_f64((total - current) as f64 / current as f64);
+            line += &format!(" ETA {:.1?}", eta);
+        }
+    } else if current > 0 {
+        line += &format!(" {}", current);
+    }
+
+    line
+}
+
+/// Draws the progress reported by the running part on the current line until finished.
+pub(crate) struct ProgressBar {
+    done: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl ProgressBar {
+    pub fn spawn() -> Self {
+        Progress::new(None);
+
+        let done = Arc::new(AtomicBool::new(false));
+
+        let handle = io::stdout().is_terminal().then(|| {
+            let done = Arc::clone(&done);
+
+            thread::spawn(move || {
+                memory::ignore_current_thread();
+
+                let start = Instant::now();
+                let mut tick = 0;
+
+                while !done.load(Ordering::Relaxed) {
+                    let line = format_line(tick, start.elapsed());
+                    print!("\r\x1b[2K{}", line.bright_black());
+                    let _ = io::stdout().flush();
+
+                    tick += 1;
+                    thread::park_timeout(REFRESH_INTERVAL);
+                }
+
+                print!("\r\x1b[2K");
+                let _ = io::stdout().flush();
+            })
+        });
+
+        Self { done, handle }
+    }
+
+    pub fn finish(mut self) {
+        self.done.store(true, Ordering::Relaxed);
+
+        if let Some(handle) = self.handle.take() {
+            handle.thread().unpark();
+            let _ = handle.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn progress_line_works() {
+        let progress = Progress::new(Some(200));
+        progress.add(50);
+        progress.set_stage("sweeping");
+
+        let line = format_line(0, Duration::from_secs(1));
+        assert!(line.starts_with("⠋ sweeping [######------------------]  25% 50/200"));
+        assert!(line.ends_with("ETA 3.0s"));
+
+        progress.set_total(0);
+        progress.inc();
+        assert_eq!(format_line(1, Duration::ZERO), "⠙ sweeping 51");
+    }
+}
@@ -0,0 +1,633 @@
+use super::{
+    generate::GeneratorFns, Alternative, Answer, Args, InputGenerator, OwnedSolution, ParamInfo,
+    PuzzleDate, PuzzleSolution, SharedSolution, SolutionError, SolutionResult,
+};
+use std::{any::Any, cell::OnceCell, error::Error, time::Duration};
+
+pub(super) type RunFn = fn(&Args, PuzzleDate) -> Result<Duration, Box<dyn Error>>;
+
+/// An input parsed by a registered solution, which can only be passed back to that solution.
+pub struct ParsedInput(Box<dyn Any>);
+
+/// A type-erased puzzle solution, as stored in a [`Registry`].
+pub struct Solution {
+    day: u32,
+    run: RunFn,
+    parse: fn(String) -> ParsedInput,
+    prepare: Option<fn(&ParsedInput) -> SolutionResult<()>>,
+    part: fn(&ParsedInput, u32) -> SolutionResult<Answer>,
+    owned: Option<OwnedFns>,
+    alternatives: Vec<ErasedAlternative>,
+    generator: Option<GeneratorFns>,
+    params: Vec<ParamInfo>,
+}
+
+type AlternativeFn = Box<dyn Fn(&ParsedInput) -> SolutionResult<Answer>>;
+
+/// An [`Alternative`] of a part, taking the input as parsed by its solution.
+struct ErasedAlternative {
+    name: &'static str,
+    part: u32,
+    solve: AlternativeFn,
+}
+
+/// The functions of solutions whose parts take ownership of the input.
+#[derive(Clone, Copy)]
+struct OwnedFns {
+    clone_input: fn(&ParsedInput) -> ParsedInput,
+    into_part: fn(ParsedInput, u32) -> SolutionResult<Answer>,
+}
+
+fn parse_erased<S: PuzzleSolution>(raw_input: String) -> ParsedInput
+where
+    S::Input: 'static,
+{
+    ParsedInput(Box::new(S::parse_input(raw_input)))
+}
+
+fn part_erased<S: PuzzleSolution>(input: &ParsedInput, part: u32) -> SolutionResult<Answer>
+where
+    S::Input: 'static,
+{
+    let input = input
+        .0
+        .downcast_ref::<S::Input>()
+        .expect("input was parsed by a different solution");
+
+    match part {
+        1 => S::part_1(input).map(Into::into),
+        2 => S::part_2(input).map(Into::into),
+        _ => Err(SolutionError::Other("invalid part selection".into())),
+    }
+}
+
+/// Erases the alternatives of a solution, given how to get their input out of a parsed input.
+fn erase_alternatives<I, O>(
+    alternatives: Vec<Alternative<I, O>>,
+    input_of: fn(&ParsedInput) -> &I,
+) -> Vec<ErasedAlternative>
+where
+    I: 'static,
+    O: Into<Answer> + 'static,
+{
+    alternatives
+        .into_iter()
+        .map(|alternative| ErasedAlternative {
+            name: alternative.name(),
+            part: alternative.part(),
+            solve: Box::new(move |input| alternative.solve(input_of(input)).map(Into::into)),
+        })
+        .collect()
+}
+
+fn downcast<I: 'static>(input: &ParsedInput) -> &I {
+    input
+        .0
+        .downcast_ref::<I>()
+        .expect("input was parsed by a different solution")
+}
+
+fn downcast_owned<S: OwnedSolution>(input: &ParsedInput) -> &S::Input
+where
+    S::Input: 'static,
+{
+    downcast::<S::Input>(input)
+}
+
+fn parse_owned_erased<S: OwnedSolution>(raw_input: String) -> ParsedInput
+where
+    S::Input: 'static,
+{
+    ParsedInput(Box::new(S::parse_input(raw_input)))
+}
+
+fn clone_owned_erased<S: OwnedSolution>(input: &ParsedInput) -> ParsedInput
+where
+    S::Input: 'static,
+{
+    ParsedInput(Box::new(downcast_owned::<S>(input).clone()))
+}
+
+fn into_part_owned_erased<S: OwnedSolution>(input: ParsedInput, part: u32) -> SolutionResult<Answer>
+where
+    S::Input: 'static,
+{
+    let input = *input
+        .0
+        .downcast::<S::Input>()
+        .expect("input was parsed by a different solution");
+
+    match part {
+        1 => S::part_1(input).map(Into::into),
+        2 => S::part_2(input).map(Into::into),
+        _ => Err(SolutionError::Other("invalid part selection".into())),
+    }
+}
+
+fn part_owned_erased<S: OwnedSolution>(input: &ParsedInput, part: u32) -> SolutionResult<Answer>
+where
+    S::Input: 'static,
+{
+    into_part_owned_erased::<S>(clone_owned_erased::<S>(input), part)
+}
+
+/// The parsed input of a shared solution, along with its shared result once computed.
+type SharedInput<S> = (
+    <S as SharedSolution>::Input,
+    OnceCell<SolutionResult<<S as SharedSolution>::Shared>>,
+);
+
+fn parse_shared_erased<S: SharedSolution>(raw_input: String) -> ParsedInput
+where
+    S::Input: 'static,
+    S::Shared: 'static,
+{
+    let input: SharedInput<S> = (S::parse_input(raw_input), OnceCell::new());
+    ParsedInput(Box::new(input))
+}
+
+/// Copies an error of a shared result, which is kept around for both parts.
+fn copy_error(error: &SolutionError) -> SolutionError {
+    match error {
+        SolutionError::Unimplemented => SolutionError::Unimplemented,
+        SolutionError::BadInput => SolutionError::BadInput,
+        SolutionError::Other(err) => SolutionError::Other(err.to_string().into()),
+    }
+}
+
+fn shared_input<S: SharedSolution>(input: &ParsedInput) -> &S::Input
+where
+    S::Input: 'static,
+    S::Shared: 'static,
+{
+    &downcast::<SharedInput<S>>(input).0
+}
+
+fn solve_shared<S: SharedSolution>(input: &ParsedInput) -> SolutionResult<(&S::Input, &S::Shared)>
+where
+    S::Input: 'static,
+    S::Shared: 'static,
+{
+    let (input, shared) = input
+        .0
+        .downcast_ref::<SharedInput<S>>()
+        .expect("input was parsed by a different solution");
+
+    match shared.get_or_init(|| S::solve(input)) {
+        Ok(shared) => Ok((input, shared)),
+        Err(e) => Err(copy_error(e)),
+    }
+}
+
+fn prepare_shared_erased<S: SharedSolution>(input: &ParsedInput) -> SolutionResult<()>
+where
+    S::Input: 'static,
+    S::Shared: 'static,
+{
+    solve_shared::<S>(input).map(|_| ())
+}
+
+fn part_shared_erased<S: SharedSolution>(input: &ParsedInput, part: u32) -> SolutionResult<Answer>
+where
+    S::Input: 'static,
+    S::Shared: 'static,
+{
+    let (input, shared) = solve_shared::<S>(input)?;
+
+    match part {
+        1 => S::part_1(input, shared).map(Into::into),
+        2 => S::part_2(input, shared).map(Into::into),
+        _ => Err(SolutionError::Other("invalid part selection".into())),
+    }
+}
+
+impl Solution {
+    pub fn new<S: PuzzleSolution>(day: u32) -> Self
+    where
+        S::Input: 'static,
+        S::Output: 'static,
+    {
+        Self {
+            day,
+            run: super::run_solution_unwrapped::<S>,
+            parse: parse_erased::<S>,
+            prepare: None,
+            part: part_erased::<S>,
+            owned: None,
+            alternatives: erase_alternatives(S::alternatives(), downcast::<S::Input>),
+            generator: None,
+            params: S::params(),
+        }
+    }
+
+    pub fn new_owned<S: OwnedSolution>(day: u32) -> Self
+    where
+        S::Input: 'static,
+        S::Output: 'static,
+    {
+        Self {
+            day,
+            run: super::run_owned_solution_unwrapped::<S>,
+            parse: parse_owned_erased::<S>,
+            prepare: None,
+            part: part_owned_erased::<S>,
+            owned: Some(OwnedFns {
+                clone_input: clone_owned_erased::<S>,
+                into_part: into_part_owned_erased::<S>,
+            }),
+            alternatives: erase_alternatives(S::alternatives(), downcast_owned::<S>),
+            generator: None,
+            params: S::params(),
+        }
+    }
+
+    pub fn new_shared<S: SharedSolution>(day: u32) -> Self
+    where
+        S::Input: 'static,
+        S::Shared: 'static,
+        S::Output: 'static,
+    {
+        Self {
+            day,
+            run: super::run_shared_solution_unwrapped::<S>,
+            parse: parse_shared_erased::<S>,
+            prepare: Some(prepare_shared_erased::<S>),
+            part: part_shared_erased::<S>,
+            owned: None,
+            alternatives: erase_alternatives(S::alternatives(), shared_input::<S>),
+            generator: None,
+            params: S::params(),
+        }
+    }
+
+    /// Like [`Solution::new`], for a solution that also generates inputs, on which its
+    /// alternatives can be cross-checked.
+    pub fn new_generated<S: PuzzleSolution + InputGenerator>(day: u32) -> Self
+    where
+        S::Input: 'static,
+        S::Output: 'static,
+    {
+        Self::new::<S>(day).generated_by::<S>()
+    }
+
+    /// Like [`Solution::new_owned`], for a solution that also generates inputs.
+    pub fn new_owned_generated<S: OwnedSolution + InputGenerator>(day: u32) -> Self
+    where
+        S::Input: 'static,
+        S::Output: 'static,
+    {
+        Self::new_owned::<S>(day).generated_by::<S>()
+    }
+
+    /// Like [`Solution::new_shared`], for a solution that also generates inputs.
+    pub fn new_shared_generated<S: SharedSolution + InputGenerator>(day: u32) -> Self
+    where
+        S::Input: 'static,
+        S::Shared: 'static,
+        S::Output: 'static,
+    {
+        Self::new_shared::<S>(day).generated_by::<S>()
+    }
+
+    /// Only called by the constructors above, so that the generator is always the solution's own.
+    fn generated_by<G: InputGenerator>(mut self) -> Self {
+        self.generator = Some(GeneratorFns::of::<G>());
+        self
+    }
+
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    pub(super) fn run_fn(&self) -> RunFn {
+        self.run
+    }
+
+    pub(super) fn generator(&self) -> Option<GeneratorFns> {
+        self.generator
+    }
+
+    /// Returns the parameters the solution declares.
+    pub fn params(&self) -> &[ParamInfo] {
+        &self.params
+    }
+
+    pub fn parse(&self, raw_input: String) -> ParsedInput {
+        (self.parse)(raw_input)
+    }
+
+    /// Returns whether the solution computes a result shared by both parts.
+    pub fn is_shared(&self) -> bool {
+        self.prepare.is_some()
+    }
+
+    /// Computes the result shared by both parts, if the solution has one. Otherwise, or if it
+    /// was already computed for this input, this does nothing.
+    pub fn prepare(&self, input: &ParsedInput) -> SolutionResult<()> {
+        match self.prepare {
+            Some(prepare) => prepare(input),
+            None => Ok(()),
+        }
+    }
+
+    pub fn part(&self, input: &ParsedInput, part: u32) -> SolutionResult<Answer> {
+        (self.part)(input, part)
+    }
+
+    /// Returns whether the parts of the solution take ownership of the input.
+    pub fn is_owned(&self) -> bool {
+        self.owned.is_some()
+    }
+
+    /// Returns a copy of the input for a part that takes ownership of it, if the solution has
+    /// such parts.
+    pub fn clone_input(&self, input: &ParsedInput) -> Option<ParsedInput> {
+        self.owned.map(|owned| (owned.clone_input)(input))
+    }
+
+    /// Solves a part of the puzzle, handing it the input if it takes ownership of it rather than
+    /// cloning it as [`Solution::part`] does.
+    pub fn into_part(&self, input: ParsedInput, part: u32) -> SolutionResult<Answer> {
+        match self.owned {
+            Some(owned) => (owned.into_part)(input, part),
+            None => self.part(&input, part),
+        }
+    }
+
+    /// Returns the names of the alternative implementations of a part.
+    pub fn alternatives(&self, part: u32) -> impl Iterator<Item = &'static str> + '_ {
+        self.alternatives
+            .iter()
+            .filter(move |alternative| alternative.part == part)
+            .map(|alternative| alternative.name)
+    }
+
+    /// Solves a part of the puzzle with one of its alternative implementations, if it has one by
+    /// that name.
+    pub fn alternative(
+        &self,
+        input: &ParsedInput,
+        part: u32,
+        name: &str,
+    ) -> Option<SolutionResult<Answer>> {
+        self.alternatives
+            .iter()
+            .find(|alternative| alternative.part == part && alternative.name == name)
+            .map(|alternative| (alternative.solve)(input))
+    }
+
+    /// Parses the input and solves a single part of the puzzle.
+    pub fn solve(&self, raw_input: String, part: u32) -> SolutionResult<Answer> {
+        self.into_part(self.parse(raw_input), part)
+    }
+}
+
+/// The solutions of a year, keyed by day.
+pub struct Registry {
+    year: u32,
+    solutions: Vec<Solution>,
+}
+
+impl Registry {
+    pub fn new(year: u32) -> Self {
+        Self {
+            year,
+            solutions: Vec::new(),
+        }
+    }
+
+    /// Registers a solution.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a solution is already registered for the same day.
+    pub fn with_solution(mut self, solution: Solution) -> Self {
+        match self
+            .solutions
+            .binary_search_by_key(&solution.day(), Solution::day)
+        {
+            Ok(_) => panic!("day {} is registered twice", solution.day()),
+            Err(index) => self.solutions.insert(index, solution),
+        }
+
+        self
+    }
+
+    /// Registers the solution for a day.
+    pub fn with<S: PuzzleSolution>(self, day: u32) -> Self
+    where
+        S::Input: 'static,
+        S::Output: 'static,
+    {
+        self.with_solution(Solution::new::<S>(day))
+    }
+
+    /// Registers the owned solution for a day.
+    pub fn with_owned<S: OwnedSolution>(self, day: u32) -> Self
+    where
+        S::Input: 'static,
+        S::Output: 'static,
+    {
+        self.with_solution(Solution::new_owned::<S>(day))
+    }
+
+    /// Registers the shared solution for a day.
+    pub fn with_shared<S: SharedSolution>(self, day: u32) -> Self
+    where
+        S::Input: 'static,
+        S::Shared: 'static,
+        S::Output: 'static,
+    {
+        self.with_solution(Solution::new_shared::<S>(day))
+    }
+
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+
+    /// Returns the registered solutions, ordered by day.
+    pub fn solutions(&self) -> &[Solution] {
+        &self.solutions
+    }
+
+    pub fn get(&self, day: u32) -> Option<&Solution> {
+        self.solutions
+            .binary_search_by_key(&day, Solution::day)
+            .ok()
+            .map(|index| &self.solutions[index])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl PuzzleSolution for Sum {
+        type Input = Vec<u32>;
+        type Output = u32;
+
+        fn parse_input(raw_input: String) -> Self::Input {
+            raw_input
+                .split_whitespace()
+                .map(|n| n.parse().unwrap())
+                .collect()
+        }
+
+        fn part_1(input: &Self::Input) -> SolutionResult<Self::Output> {
+            Ok(input.iter().sum())
+        }
+
+        fn alternatives() -> Vec<Alternative<Self::Input, Self::Output>> {
+            vec![
+                Alternative::new("loop", 1, |input| {
+                    let mut sum = 0;
+                    for n in input {
+                        sum += n;
+                    }
+                    Ok(sum)
+                }),
+                Alternative::new("max", 1, |input| {
+                    Ok(input.iter().copied().max().unwrap_or(0))
+                }),
+            ]
+        }
+    }
+
+    struct SharedSum;
+
+    impl SharedSolution for SharedSum {
+        type Input = Vec<u32>;
+        type Shared = u32;
+        type Output = u32;
+
+        fn parse_input(raw_input: String) -> Self::Input {
+            Sum::parse_input(raw_input)
+        }
+
+        fn solve(input: &Self::Input) -> SolutionResult<Self::Shared> {
+            Ok(input.iter().sum())
+        }
+
+        fn part_1(_input: &Self::Input, shared: &Self::Shared) -> SolutionResult<Self::Output> {
+            Ok(*shared)
+        }
+
+        fn part_2(input: &Self::Input, shared: &Self::Shared) -> SolutionResult<Self::Output> {
+            Ok(shared * input.len() as u32)
+        }
+
+        fn alternatives() -> Vec<Alternative<Self::Input, Self::Output>> {
+            vec![Alternative::new("unshared", 2, |input| {
+                Ok(input.iter().sum::<u32>() * input.len() as u32)
+            })]
+        }
+    }
+
+    #[test]
+    fn registry_works() {
+        let registry = Registry::new(2024).with::<Sum>(3).with::<Sum>(1);
+        let days = registry
+            .solutions()
+            .iter()
+            .map(Solution::day)
+            .collect::<Vec<_>>();
+
+        assert_eq!(days, [1, 3]);
+        assert!(registry.get(2).is_none());
+
+        let solution = registry.get(3).unwrap();
+        assert_eq!(
+            solution.solve("1 2 3".to_string(), 1).unwrap(),
+            Answer::from(6)
+        );
+        assert!(matches!(
+            solution.solve("1 2 3".to_string(), 2),
+            Err(SolutionError::Unimplemented)
+        ));
+    }
+
+    #[test]
+    fn alternatives_work() {
+        let solution = Solution::new::<Sum>(1);
+        let input = solution.parse("1 2 3".to_string());
+
+        assert_eq!(
+            solution.alternatives(1).collect::<Vec<_>>(),
+            ["loop", "max"]
+        );
+        assert_eq!(solution.alternatives(2).count(), 0);
+        assert_eq!(
+            solution.alternative(&input, 1, "loop").unwrap().unwrap(),
+            Answer::from(6)
+        );
+        assert_eq!(
+            solution.alternative(&input, 1, "max").unwrap().unwrap(),
+            Answer::from(3)
+        );
+        assert!(solution.alternative(&input, 2, "loop").is_none());
+    }
+
+    struct Drain;
+
+    impl OwnedSolution for Drain {
+        type Input = Vec<u32>;
+        type Output = u32;
+
+        fn parse_input(raw_input: String) -> Self::Input {
+            Sum::parse_input(raw_input)
+        }
+
+        fn part_1(mut input: Self::Input) -> SolutionResult<Self::Output> {
+            Ok(input.drain(..).sum())
+        }
+
+        fn part_2(mut input: Self::Input) -> SolutionResult<Self::Output> {
+            Ok(input.pop().unwrap_or_default())
+        }
+
+        fn alternatives() -> Vec<Alternative<Self::Input, Self::Output>> {
+            vec![Alternative::new("last", 2, |input| {
+                Ok(input.last().copied().unwrap_or_default())
+            })]
+        }
+    }
+
+    #[test]
+    fn owned_solution_works() {
+        let solution = Solution::new_owned::<Drain>(1);
+        let input = solution.parse("1 2 3".to_string());
+        assert!(solution.is_owned());
+
+        assert_eq!(solution.part(&input, 1).unwrap(), Answer::from(6));
+        assert_eq!(solution.part(&input, 2).unwrap(), Answer::from(3));
+
+        let copy = solution.clone_input(&input).unwrap();
+        assert_eq!(solution.into_part(copy, 1).unwrap(), Answer::from(6));
+        assert_eq!(
+            solution.alternative(&input, 2, "last").unwrap().unwrap(),
+            Answer::from(3)
+        );
+        assert_eq!(solution.into_part(input, 2).unwrap(), Answer::from(3));
+    }
+
+    #[test]
+    fn shared_solution_works() {
+        let solution = Solution::new_shared::<SharedSum>(1);
+        let input = solution.parse("1 2 3".to_string());
+
+        assert!(solution.is_shared());
+        assert!(solution.prepare(&input).is_ok());
+        assert_eq!(solution.part(&input, 1).unwrap(), Answer::from(6));
+        assert_eq!(solution.part(&input, 2).unwrap(), Answer::from(18));
+        assert_eq!(solution.alternatives(2).collect::<Vec<_>>(), ["unshared"]);
+        assert_eq!(
+            solution
+                .alternative(&input, 2, "unshared")
+                .unwrap()
+                .unwrap(),
+            Answer::from(18)
+        );
+    }
+}
@@ -0,0 +1,301 @@
+use std::fmt::Display;
+use std::time::Duration;
+#[cfg(feature = "spans")]
+use std::{cell::RefCell, time::Instant};
+
+/// The timings collected for a span name at one position of the span tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpanNode {
+    pub name: &'static str,
+    pub total: Duration,
+    pub calls: u64,
+    pub children: Vec<SpanNode>,
+}
+
+/// Displays span trees as indented lines with their total time and call count.
+pub struct SpanTree<'a>(pub &'a [SpanNode]);
+
+impl SpanTree<'_> {
+    fn name_width(nodes: &[SpanNode], depth: usize) -> usize {
+        nodes
+            .iter()
+            .map(|node| {
+                (2 * depth + node.name.len()).max(Self::name_width(&node.children, depth + 1))
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn fmt_nodes(
+        f: &mut std::fmt::Formatter<'_>,
+        nodes: &[SpanNode],
+        depth: usize,
+        width: usize,
+    ) -> std::fmt::Result {
+        for node in nodes {
+            let label = format!("{}{}", "  ".repeat(depth), node.name);
+            let calls = if node.calls == 1 { "call" } else { "calls" };
+            writeln!(
+                f,
+                "  {:<width$}  {:>10.2?}  ({} {})",
+                label,
+                node.total,
+                node.calls,
+                calls,
+                width = width
+            )?;
+            Self::fmt_nodes(f, &node.children, depth + 1, width)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for SpanTree<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let width = Self::name_width(self.0, 0);
+        Self::fmt_nodes(f, self.0, 0, width)
+    }
+}
+
+#[cfg(feature = "spans")]
+#[derive(Debug, Default)]
+struct Recorder {
+    /// Node 0 is an unnamed root holding the top-level spans.
+    nodes: Vec<(SpanNode, Vec<usize>)>,
+    stack: Vec<usize>,
+    /// Bumped whenever the recorder is cleared, so that spans still running across a reset don't
+    /// record into nodes that no longer exist.
+    generation: u64,
+}
+
+#[cfg(feature = "spans")]
+impl Recorder {
+    fn enter(&mut self, name: &'static str) -> usize {
+        if self.nodes.is_empty() {
+            self.nodes.push((
+                SpanNode {
+                    name: "",
+                    total: Duration::ZERO,
+                    calls: 0,
+                    children: Vec::new(),
+                },
+                Vec::new(),
+            ));
+        }
+
+        let parent = self.stack.last().copied().unwrap_or(0);
+        let existing = self.nodes[parent]
+            .1
+            .iter()
+            .copied()
+            .find(|&child| self.nodes[child].0.name == name);
+
+        let index = existing.unwrap_or_else(|| {
+            self.nodes.push((
+                SpanNode {
+                    name,
+                    total: Duration::ZERO,
+                    calls: 0,
+                    children: Vec::new(),
+                },
+                Vec::new(),
+            ));
+            let index = self.nodes.len() - 1;
+            self.nodes[parent].1.push(index);
+            index
+        });
+
+        self.stack.push(index);
+        index
+    }
+
+    fn exit(&mut self, index: usize, generation: u64, elapsed: Duration) {
+        if generation != self.generation {
+            return;
+        }
+
+        if let Some(position) = self.stack.iter().rposition(|&i| i == index) {
+            self.stack.truncate(position);
+        }
+
+        let node = &mut self.nodes[index].0;
+        node.total += elapsed;
+        node.calls += 1;
+    }
+
+    fn build(&self, index: usize) -> SpanNode {
+        let (node, children) = &self.nodes[index];
+
+        SpanNode {
+            children: children.iter().map(|&child| self.build(child)).collect(),
+            ..node.clone()
+        }
+    }
+
+    fn clear(&mut self) {
+        self.nodes.clear();
+        self.stack.clear();
+        self.generation += 1;
+    }
+}
+
+#[cfg(feature = "spans")]
+thread_local! {
+    static RECORDER: RefCell<Recorder> = RefCell::default();
+}
+
+/// A running span, which records its timing when dropped.
+#[cfg(feature = "spans")]
+#[must_use = "the span ends as soon as it is dropped"]
+pub struct Span {
+    index: usize,
+    generation: u64,
+    start: Instant,
+}
+
+#[cfg(feature = "spans")]
+impl Drop for Span {
+    fn drop(&mut self) {
+        let elapsed = self.start.elapsed();
+        RECORDER.with_borrow_mut(|recorder| recorder.exit(self.index, self.generation, elapsed));
+    }
+}
+
+/// A running span. Spans are only recorded when `aoc-lib` is built with the `spans` feature, so
+/// this is a no-op.
+#[cfg(not(feature = "spans"))]
+#[must_use = "the span ends as soon as it is dropped"]
+pub struct Span;
+
+/// Starts timing a section of a solution, until the returned guard is dropped. Spans started
+/// while another one is running are nested under it, and repeated spans with the same name are
+/// merged, so the runner can show where a part spends its time with `--spans`.
+///
+/// ```ignore
+/// let _t = aoc_lib::span("dijkstra");
+/// ```
+#[inline(always)]
+pub fn span(name: &'static str) -> Span {
+    #[cfg(feature = "spans")]
+    {
+        let (index, generation) =
+            RECORDER.with_borrow_mut(|recorder| (recorder.enter(name), recorder.generation));
+        Span {
+            index,
+            generation,
+            start: Instant::now(),
+        }
+    }
+
+    #[cfg(not(feature = "spans"))]
+    {
+        let _ = name;
+        Span
+    }
+}
+
+pub(crate) const ENABLED: bool = cfg!(feature = "spans");
+
+/// Clears the spans recorded on the current thread.
+pub(crate) fn reset() {
+    #[cfg(feature = "spans")]
+    RECORDER.with_borrow_mut(Recorder::clear);
+}
+
+/// Returns the spans recorded on the current thread since the last reset.
+pub(crate) fn take() -> Vec<SpanNode> {
+    #[cfg(feature = "spans")]
+    {
+        RECORDER.with_borrow_mut(|recorder| {
+            let nodes = match recorder.nodes.is_empty() {
+                true => Vec::new(),
+                false => recorder.build(0).children,
+            };
+            recorder.clear();
+            nodes
+        })
+    }
+
+    #[cfg(not(feature = "spans"))]
+    Vec::new()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn span_tree_display_works() {
+        let leaf = |name, millis, calls| SpanNode {
+            name,
+            total: Duration::from_millis(millis),
+            calls,
+            children: Vec::new(),
+        };
+        let nodes = [
+            SpanNode {
+                children: vec![leaf("dijkstra", 4, 1)],
+                ..leaf("distances", 5, 1)
+            },
+            leaf("cheats", 12, 3),
+        ];
+
+        assert_eq!(
+            SpanTree(&nodes).to_string(),
+            concat!(
+                "  distances       5.00ms  (1 call)\n",
+                "    dijkstra      4.00ms  (1 call)\n",
+                "  cheats         12.00ms  (3 calls)\n",
+            )
+        );
+    }
+
+    #[cfg(feature = "spans")]
+    #[test]
+    fn spans_are_nested_and_merged() {
+        reset();
+
+        for _ in 0..3 {
+            let _outer = span("outer");
+            let _inner = span("inner");
+        }
+        drop(span("other"));
+
+        let nodes = take();
+
+        assert_eq!(nodes.len(), 2);
+        assert_eq!((nodes[0].name, nodes[0].calls), ("outer", 3));
+        assert_eq!(nodes[0].children.len(), 1);
+        assert_eq!(
+            (nodes[0].children[0].name, nodes[0].children[0].calls),
+            ("inner", 3)
+        );
+        assert_eq!((nodes[1].name, nodes[1].calls), ("other", 1));
+        assert!(take().is_empty());
+    }
+
+    #[cfg(feature = "spans")]
+    #[test]
+    fn spans_outliving_a_reset_are_ignored() {
+        reset();
+
+        let stale = span("stale");
+        reset();
+        let _fresh = span("fresh");
+        drop(stale);
+
+        let late = span("late");
+        let nodes = take();
+        drop(late);
+
+        assert_eq!(nodes.len(), 1);
+        assert_eq!((nodes[0].name, nodes[0].calls), ("fresh", 0));
+        assert_eq!(nodes[0].children.len(), 1);
+        assert_eq!(
+            (nodes[0].children[0].name, nodes[0].children[0].calls),
+            ("late", 0)
+        );
+        assert!(take().is_empty());
+    }
+}
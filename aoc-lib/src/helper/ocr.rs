@@ -0,0 +1,318 @@
+use std::{fmt::Display, ops::Range};
+
+const ALPHABET_6: [(char, &str); 18] = [
+    ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+    ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+    ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+    ('E', "####\n#...\n###.\n#...\n#...\n####"),
+    ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+    ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+    ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+    ('I', ".###\n..#.\n..#.\n..#.\n..#.\n.###"),
+    ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+    ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+    ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+    ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+    ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+    ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+    ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('Y', "#...#\n#...#\n.#.#.\n..#..\n..#..\n..#.."),
+    ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+];
+
+#[rustfmt::skip]
+const ALPHABET_10: [(char, &str); 15] = [
+    ('A', "..##..\n.#..#.\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#"),
+    ('B', "#####.\n#....#\n#....#\n#....#\n#####.\n#....#\n#....#\n#....#\n#....#\n#####."),
+    ('C', ".####.\n#....#\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#....#\n.####."),
+    ('E', "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n######"),
+    ('F', "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n#....."),
+    ('G', ".####.\n#....#\n#.....\n#.....\n#.....\n#..###\n#....#\n#....#\n#...##\n.###.#"),
+    ('H', "#....#\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#\n#....#"),
+    ('J', "...###\n....#.\n....#.\n....#.\n....#.\n....#.\n....#.\n#...#.\n#...#.\n.###.."),
+    ('K', "#....#\n#...#.\n#..#..\n#.#...\n##....\n##....\n#.#...\n#..#..\n#...#.\n#....#"),
+    ('L', "#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n######"),
+    ('N', "#....#\n##...#\n##...#\n#.#..#\n#.#..#\n#..#.#\n#..#.#\n#...##\n#...##\n#....#"),
+    ('P', "#####.\n#....#\n#....#\n#....#\n#####.\n#.....\n#.....\n#.....\n#.....\n#....."),
+    ('R', "#####.\n#....#\n#....#\n#....#\n#####.\n#..#..\n#...#.\n#...#.\n#....#\n#....#"),
+    ('X', "#....#\n#....#\n.#..#.\n.#..#.\n..##..\n..##..\n.#..#.\n.#..#.\n#....#\n#....#"),
+    ('Z', "######\n.....#\n.....#\n....#.\n...#..\n..#...\n.#....\n#.....\n#.....\n######"),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OcrError {
+    InvalidChar(char),
+    UnsupportedHeight(usize),
+    UnknownGlyph { column: usize, glyph: String },
+}
+
+impl std::error::Error for OcrError {}
+
+impl Display for OcrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidChar(ch) => write!(f, "invalid pixel character: {:?}", ch),
+            Self::UnsupportedHeight(height) => {
+                write!(
+                    f,
+                    "unsupported letter height: {} (expected 6 or 10)",
+                    height
+                )
+            }
+            Self::UnknownGlyph { column, glyph } => {
+                write!(f, "unknown glyph at column {}:\n{}", column, glyph)
+            }
+        }
+    }
+}
+
+fn render(rows: &[Vec<bool>]) -> String {
+    rows.iter()
+        .map(|row| {
+            row.iter()
+                .map(|&lit| if lit { '#' } else { '.' })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Removes the blank columns around a glyph, so glyphs can be matched regardless of how much
+/// padding the font leaves on either side.
+fn trim_columns(rows: &[Vec<bool>]) -> Vec<Vec<bool>> {
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let is_lit = |j: usize| rows.iter().any(|row| row.get(j).copied().unwrap_or(false));
+
+    let Some(start) = (0..width).find(|&j| is_lit(j)) else {
+        return vec![vec![]; rows.len()];
+    };
+    let end = (0..width).rfind(|&j| is_lit(j)).unwrap() + 1;
+
+    rows.iter()
+        .map(|row| {
+            (start..end)
+                .map(|j| row.get(j).copied().unwrap_or(false))
+                .collect()
+        })
+        .collect()
+}
+
+fn parse_pixels(s: &str) -> Result<Vec<Vec<bool>>, OcrError> {
+    s.lines()
+        .map(|line| {
+            line.chars()
+                .map(|ch| match ch {
+                    '#' | '█' => Ok(true),
+                    '.' | ' ' => Ok(false),
+                    _ => Err(OcrError::InvalidChar(ch)),
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn find_letter(glyph: &str, height: usize) -> Option<char> {
+    let alphabet: &[(char, &str)] = match height {
+        6 => &ALPHABET_6,
+        _ => &ALPHABET_10,
+    };
+
+    alphabet.iter().find_map(|&(letter, pattern)| {
+        let pattern = parse_pixels(pattern).unwrap();
+        (render(&trim_columns(&pattern)) == glyph).then_some(letter)
+    })
+}
+
+/// Splits the columns of a drawing into one range per letter. Puzzle outputs draw letters in
+/// fixed-width cells (4 columns and a blank one for the small font, 6 columns and two blank ones
+/// for the large one), which separates letters even when they touch, like a small `Y` followed by
+/// an `H`. Drawings whose width isn't a whole number of cells are split on blank columns instead.
+fn letter_columns(rows: &[Vec<bool>], height: usize) -> Vec<Range<usize>> {
+    let cell_width = if height == 6 { 5 } else { 8 };
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let is_lit = |j: usize| rows.iter().any(|row| row.get(j).copied().unwrap_or(false));
+
+    if width % cell_width == 0 {
+        return (0..width)
+            .step_by(cell_width)
+            .map(|start| start..(start + cell_width))
+            .filter(|cell| cell.clone().any(is_lit))
+            .collect();
+    }
+
+    let mut columns = Vec::new();
+    let mut j = 0;
+
+    while j < width {
+        if !is_lit(j) {
+            j += 1;
+            continue;
+        }
+
+        let start = j;
+        while j < width && is_lit(j) {
+            j += 1;
+        }
+
+        columns.push(start..j);
+    }
+
+    columns
+}
+
+/// Reads the capital letters drawn by a grid of lit pixels, using the 4×6 or 6×10 block fonts
+/// that show up in puzzle outputs. Letters are split on the font's cell width when the grid is a
+/// whole number of cells wide, and on blank columns otherwise.
+pub fn read_letters<R: AsRef<[bool]>>(pixels: &[R]) -> Result<String, OcrError> {
+    let rows = pixels
+        .iter()
+        .map(|row| row.as_ref().to_vec())
+        .skip_while(|row| !row.contains(&true))
+        .collect::<Vec<_>>();
+    let height = rows
+        .iter()
+        .rposition(|row| row.contains(&true))
+        .map_or(0, |i| i + 1);
+    let rows = &rows[..height];
+
+    if height != 6 && height != 10 {
+        return Err(OcrError::UnsupportedHeight(height));
+    }
+
+    let mut letters = String::new();
+
+    for columns in letter_columns(rows, height) {
+        let glyph = rows
+            .iter()
+            .map(|row| {
+                columns
+                    .clone()
+                    .map(|k| row.get(k).copied().unwrap_or(false))
+                    .collect()
+            })
+            .collect::<Vec<_>>();
+        let glyph = render(&trim_columns(&glyph));
+
+        match find_letter(&glyph, height) {
+            Some(letter) => letters.push(letter),
+            None => {
+                return Err(OcrError::UnknownGlyph {
+                    column: columns.start,
+                    glyph,
+                })
+            }
+        }
+    }
+
+    Ok(letters)
+}
+
+/// Like [`read_letters`], but reads the pixels from text where `#` is lit and `.` is not.
+pub fn read_letters_str(s: &str) -> Result<String, OcrError> {
+    read_letters(&parse_pixels(s)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn join_glyphs(alphabet: &[(char, &str)]) -> String {
+        let height = alphabet[0].1.lines().count();
+
+        alphabet
+            .iter()
+            .fold(vec![String::new(); height], |mut rows, (_, pattern)| {
+                for (row, line) in rows.iter_mut().zip(pattern.lines()) {
+                    row.push_str(line);
+                    row.push('.');
+                }
+                rows
+            })
+            .join("\n")
+    }
+
+    #[test]
+    fn small_font_works() {
+        assert_eq!(
+            read_letters_str(&join_glyphs(&ALPHABET_6)),
+            Ok("ABCEFGHIJKLOPRSUYZ".to_owned())
+        );
+
+        let pixels = "\
+#..#.###..####
+#..#..#...#...
+####..#...###.
+#..#..#...#...
+#..#..#...#...
+#..#.###..####";
+        assert_eq!(read_letters_str(pixels), Ok("HIE".to_owned()));
+    }
+
+    #[test]
+    fn large_font_works() {
+        assert_eq!(
+            read_letters_str(&join_glyphs(&ALPHABET_10)),
+            Ok("ABCEFGHJKLNPRXZ".to_owned())
+        );
+    }
+
+    #[test]
+    fn touching_letters_are_split_on_cells() {
+        let pixels = "\
+#...##..#.
+#...##..#.
+.#.#.####.
+..#..#..#.
+..#..#..#.
+..#..#..#.";
+        assert_eq!(read_letters_str(pixels), Ok("YH".to_owned()));
+
+        let pixels = "\
+#....#..#....#..
+#....#..#....#..
+.#..#...#....#..
+.#..#...#....#..
+..##....######..
+..##....#....#..
+.#..#...#....#..
+.#..#...#....#..
+#....#..#....#..
+#....#..#....#..";
+        assert_eq!(read_letters_str(pixels), Ok("XH".to_owned()));
+    }
+
+    #[test]
+    fn blank_border_is_ignored() {
+        let pixels = [
+            vec![false; 6],
+            vec![false, true, true, true, true, false],
+            vec![false, true, false, false, false, false],
+            vec![false, true, true, true, false, false],
+            vec![false, true, false, false, false, false],
+            vec![false, true, false, false, false, false],
+            vec![false, true, false, false, false, false],
+            vec![false; 6],
+        ];
+
+        assert_eq!(read_letters(&pixels), Ok("F".to_owned()));
+    }
+
+    #[test]
+    fn unknown_glyph_is_reported() {
+        let pixels = "####.#..#\n#..#.#..#\n#..#.#..#\n#..#.#..#\n#..#.#..#\n####..##.";
+
+        assert_eq!(
+            read_letters_str(pixels),
+            Err(OcrError::UnknownGlyph {
+                column: 0,
+                glyph: "####\n#..#\n#..#\n#..#\n#..#\n####".to_owned()
+            })
+        );
+        assert_eq!(
+            read_letters_str("#\n#\n#"),
+            Err(OcrError::UnsupportedHeight(3))
+        );
+        assert_eq!(read_letters_str("#x"), Err(OcrError::InvalidChar('x')));
+    }
+}
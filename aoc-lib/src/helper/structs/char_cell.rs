@@ -0,0 +1,72 @@
+/// A cell of a puzzle map that is written as a single character, such as a wall `#`.
+///
+/// The [`char_cell!`](crate::char_cell) macro implements it for an enum by giving each variant
+/// its character.
+pub trait CharCell: Sized {
+    fn from_char(ch: char) -> Option<Self>;
+
+    fn to_char(&self) -> char;
+}
+
+impl CharCell for char {
+    fn from_char(ch: char) -> Option<Self> {
+        Some(ch)
+    }
+
+    fn to_char(&self) -> char {
+        *self
+    }
+}
+
+/// Defines an enum of map cells along with their characters, implementing [`CharCell`] and
+/// `Display` for it:
+///
+/// ```
+/// aoc_lib::char_cell! {
+///     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///     enum Tile {
+///         Wall = '#',
+///         Empty = '.',
+///     }
+/// }
+///
+/// use aoc_lib::helper::structs::CharCell;
+///
+/// assert_eq!(Tile::from_char('#'), Some(Tile::Wall));
+/// assert_eq!(Tile::Empty.to_string(), ".");
+/// ```
+#[macro_export]
+macro_rules! char_cell {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $($(#[$variant_meta:meta])* $variant:ident = $ch:literal),+ $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis enum $name {
+            $($(#[$variant_meta])* $variant),+
+        }
+
+        impl $crate::helper::structs::CharCell for $name {
+            fn from_char(ch: char) -> ::std::option::Option<Self> {
+                match ch {
+                    $($ch => ::std::option::Option::Some(Self::$variant),)+
+                    _ => ::std::option::Option::None,
+                }
+            }
+
+            fn to_char(&self) -> char {
+                match self {
+                    $(Self::$variant => $ch,)+
+                }
+            }
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                write!(f, "{}", $crate::helper::structs::CharCell::to_char(self))
+            }
+        }
+    };
+}
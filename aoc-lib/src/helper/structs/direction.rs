@@ -1,3 +1,7 @@
+use num::Signed;
+
+use super::{Index2D, Vector2D};
+
 pub const DIRECTIONS: [Direction; 4] = [
     Direction::Up,
     Direction::Right,
@@ -14,6 +18,27 @@ pub enum Direction {
 }
 
 impl Direction {
+    /// Parses a direction from an arrow (`^>v<`), a letter (`UDLR`) or a compass point (`NESW`).
+    pub fn from_char(ch: char) -> Option<Self> {
+        match ch {
+            '^' | 'U' | 'N' => Some(Self::Up),
+            '>' | 'R' | 'E' => Some(Self::Right),
+            'v' | 'D' | 'S' => Some(Self::Down),
+            '<' | 'L' | 'W' => Some(Self::Left),
+            _ => None,
+        }
+    }
+
+    /// Returns the arrow pointing in the direction, for rendering.
+    pub fn to_char(&self) -> char {
+        match self {
+            Self::Up => '^',
+            Self::Right => '>',
+            Self::Down => 'v',
+            Self::Left => '<',
+        }
+    }
+
     pub fn rotated_clockwise(&self) -> Self {
         match self {
             Self::Up => Self::Right,
@@ -40,4 +65,137 @@ impl Direction {
             Self::Left => Self::Down,
         }
     }
+
+    /// Turns by a number of quarter turns, clockwise if positive.
+    pub fn turned(&self, quarter_turns: i32) -> Self {
+        DIRECTIONS[(*self as i32 + quarter_turns).rem_euclid(4) as usize]
+    }
+
+    /// The offset of a step in the direction, where up decreases `i` and left decreases `j`.
+    pub fn offset<T: Signed>(&self) -> Index2D<T> {
+        match self {
+            Self::Up => Index2D::new(-T::one(), T::zero()),
+            Self::Right => Index2D::new(T::zero(), T::one()),
+            Self::Down => Index2D::new(T::one(), T::zero()),
+            Self::Left => Index2D::new(T::zero(), -T::one()),
+        }
+    }
+
+    /// The unit vector pointing in the direction, with `y` growing downwards as in puzzle maps.
+    pub fn vector<T: Signed>(&self) -> Vector2D<T> {
+        let Index2D { i, j } = self.offset();
+        Vector2D::new(j, i)
+    }
+}
+
+pub const DIRECTIONS_8: [Direction8; 8] = [
+    Direction8::Up,
+    Direction8::UpRight,
+    Direction8::Right,
+    Direction8::DownRight,
+    Direction8::Down,
+    Direction8::DownLeft,
+    Direction8::Left,
+    Direction8::UpLeft,
+];
+
+/// A direction that can also be diagonal, such as the directions of a word search.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    pub fn is_diagonal(&self) -> bool {
+        *self as usize % 2 == 1
+    }
+
+    /// Turns by a number of eighth turns, clockwise if positive.
+    pub fn turned(&self, eighth_turns: i32) -> Self {
+        DIRECTIONS_8[(*self as i32 + eighth_turns).rem_euclid(8) as usize]
+    }
+
+    pub fn rotated_45_clockwise(&self) -> Self {
+        self.turned(1)
+    }
+
+    pub fn rotated_45_counter_clockwise(&self) -> Self {
+        self.turned(-1)
+    }
+
+    pub fn backwards(&self) -> Self {
+        self.turned(4)
+    }
+
+    /// The offset of a step in the direction, where up decreases `i` and left decreases `j`.
+    pub fn offset<T: Signed>(&self) -> Index2D<T> {
+        let straight = |direction: Direction| direction.offset::<T>();
+
+        match self {
+            Self::Up => straight(Direction::Up),
+            Self::UpRight => straight(Direction::Up) + straight(Direction::Right),
+            Self::Right => straight(Direction::Right),
+            Self::DownRight => straight(Direction::Down) + straight(Direction::Right),
+            Self::Down => straight(Direction::Down),
+            Self::DownLeft => straight(Direction::Down) + straight(Direction::Left),
+            Self::Left => straight(Direction::Left),
+            Self::UpLeft => straight(Direction::Up) + straight(Direction::Left),
+        }
+    }
+
+    /// The vector of a step in the direction, with `y` growing downwards as in puzzle maps.
+    pub fn vector<T: Signed>(&self) -> Vector2D<T> {
+        let Index2D { i, j } = self.offset();
+        Vector2D::new(j, i)
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Self::Up,
+            Direction::Right => Self::Right,
+            Direction::Down => Self::Down,
+            Direction::Left => Self::Left,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn directions_work() {
+        assert_eq!(Direction::from_char('v'), Some(Direction::Down));
+        assert_eq!(Direction::from_char('L'), Some(Direction::Left));
+        assert_eq!(Direction::from_char('N'), Some(Direction::Up));
+        assert_eq!(Direction::from_char('x'), None);
+        assert_eq!(Direction::Right.to_char(), '>');
+
+        assert_eq!(Direction::Up.turned(3), Direction::Left);
+        assert_eq!(Direction::Up.turned(-5), Direction::Left);
+        assert_eq!(Direction::Left.offset::<i32>(), Index2D::new(0, -1));
+        assert_eq!(Direction::Up.vector::<i32>(), Vector2D::new(0, -1));
+    }
+
+    #[test]
+    fn diagonal_directions_work() {
+        assert_eq!(Direction8::Up.rotated_45_clockwise(), Direction8::UpRight);
+        assert_eq!(
+            Direction8::Up.rotated_45_counter_clockwise(),
+            Direction8::UpLeft
+        );
+        assert_eq!(Direction8::UpRight.backwards(), Direction8::DownLeft);
+        assert!(Direction8::DownLeft.is_diagonal());
+        assert_eq!(Direction8::DownLeft.offset::<i32>(), Index2D::new(1, -1));
+        assert_eq!(Direction8::from(Direction::Left), Direction8::Left);
+    }
 }
@@ -0,0 +1,486 @@
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use crate::visual::{Cell, Frame};
+
+use super::{CharCell, Index2D, Torus, DIRECTIONS};
+
+/// A dense grid stored row by row, where `Index2D { i, j }` is the cell in row `i` and column `j`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    height: usize,
+    width: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from its cells, listed row by row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there aren't exactly `height * width` cells.
+    pub fn from_vec(height: usize, width: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), height * width, "wrong number of cells");
+
+        Self {
+            height,
+            width,
+            cells,
+        }
+    }
+
+    /// Creates a grid from its rows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rows don't all have the same length.
+    pub fn from_rows<R: IntoIterator<Item = T>>(rows: impl IntoIterator<Item = R>) -> Self {
+        let mut height = 0;
+        let mut width = None;
+        let mut cells = Vec::new();
+
+        for row in rows {
+            let len = cells.len();
+            cells.extend(row);
+
+            let row_width = cells.len() - len;
+            assert_eq!(
+                *width.get_or_insert(row_width),
+                row_width,
+                "rows differ in length"
+            );
+
+            height += 1;
+        }
+
+        Self {
+            height,
+            width: width.unwrap_or(0),
+            cells,
+        }
+    }
+
+    /// Parses a grid from lines of text, mapping each character to a cell.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lines don't all have the same length.
+    pub fn parse<F: FnMut(char) -> T>(text: &str, mut f: F) -> Self {
+        Self::from_rows(
+            text.lines()
+                .map(|line| line.chars().map(&mut f).collect::<Vec<_>>()),
+        )
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn contains(&self, pos: Index2D<usize>) -> bool {
+        pos.i < self.height && pos.j < self.width
+    }
+
+    /// Returns the orthogonal neighbors of a position that lie within the grid.
+    pub fn neighbors(&self, pos: Index2D<usize>) -> impl Iterator<Item = Index2D<usize>> {
+        pos.neighbors_within(Index2D::new(self.height, self.width))
+    }
+
+    /// Returns the space of the grid with its edges wrapping around.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the grid is empty.
+    pub fn torus(&self) -> Torus<isize> {
+        Torus::new(self.width as isize, self.height as isize)
+    }
+
+    /// Returns the 4 orthogonal neighbors of a position, going around the edges of the grid to
+    /// the other side, up, right, down then left.
+    pub fn neighbors_wrapping(&self, pos: Index2D<usize>) -> impl Iterator<Item = Index2D<usize>> {
+        let torus = self.torus();
+        let pos = pos.to_signed();
+
+        DIRECTIONS.into_iter().map(move |direction| {
+            let neighbor = torus.wrap_index(pos + direction.offset());
+            Index2D::new(neighbor.i as usize, neighbor.j as usize)
+        })
+    }
+
+    /// Returns the cell at a position anywhere, as if the grid repeated in every direction.
+    pub fn get_wrapping(&self, pos: Index2D<isize>) -> &T {
+        let pos = self.torus().wrap_index(pos);
+        &self[Index2D::new(pos.i as usize, pos.j as usize)]
+    }
+
+    pub fn get(&self, pos: Index2D<usize>) -> Option<&T> {
+        if self.contains(pos) {
+            self.cells.get(pos.i * self.width + pos.j)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: Index2D<usize>) -> Option<&mut T> {
+        if self.contains(pos) {
+            self.cells.get_mut(pos.i * self.width + pos.j)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the cells of row `i`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the row is out of the grid.
+    pub fn row(&self, i: usize) -> &[T] {
+        assert!(i < self.height, "row {} is out of the grid", i);
+        &self.cells[i * self.width..(i + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1)).take(self.height)
+    }
+
+    /// Returns the cells of column `j`, from top to bottom.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the column is out of the grid.
+    pub fn column(&self, j: usize) -> impl Iterator<Item = &T> {
+        assert!(j < self.width, "column {} is out of the grid", j);
+        self.cells.iter().skip(j).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|j| self.column(j))
+    }
+
+    /// Returns every position of the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Index2D<usize>> {
+        let width = self.width;
+        (0..self.height).flat_map(move |i| (0..width).map(move |j| Index2D::new(i, j)))
+    }
+
+    /// Returns every cell along with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Index2D<usize>, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Index2D<usize>, &mut T)> {
+        self.positions().zip(&mut self.cells)
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            height: self.height,
+            width: self.width,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Renders the grid as a frame for visualizations.
+    pub fn to_frame<C: Into<Cell>, F: FnMut(&T) -> C>(&self, mut f: F) -> Frame {
+        let mut frame = Frame::new(self.height, self.width);
+
+        for (pos, cell) in self.iter() {
+            frame.set(pos.i, pos.j, f(cell));
+        }
+
+        frame
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(height: usize, width: usize, value: T) -> Self {
+        Self::from_vec(height, width, vec![value; height * width])
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// Returns the position of the first cell equal to `value`, row by row.
+    pub fn find(&self, value: &T) -> Option<Index2D<usize>> {
+        self.iter()
+            .find(|(_, cell)| *cell == value)
+            .map(|(pos, _)| pos)
+    }
+
+    /// Returns the positions of all the cells equal to `value`, row by row.
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Index2D<usize>> + 'a {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(pos, _)| pos)
+    }
+}
+
+impl<T: CharCell + Clone> Grid<T> {
+    /// Parses a grid of cells from their characters.
+    pub fn parse_cells(text: &str) -> Result<Self, GridParseError> {
+        GridParser::new().parse(text).map(|parsed| parsed.grid)
+    }
+}
+
+/// Why a grid couldn't be parsed. Positions are shown 1-based, as in a text editor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridParseError {
+    UnknownChar {
+        ch: char,
+        pos: Index2D<usize>,
+    },
+    RepeatedMarker {
+        ch: char,
+        first: Index2D<usize>,
+        second: Index2D<usize>,
+    },
+    RaggedRow {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl Display for GridParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownChar { ch, pos } => write!(
+                f,
+                "unknown character {:?} at line {}, column {}",
+                ch,
+                pos.i + 1,
+                pos.j + 1
+            ),
+            Self::RepeatedMarker { ch, first, second } => write!(
+                f,
+                "{:?} appears at line {}, column {} and again at line {}, column {}",
+                ch,
+                first.i + 1,
+                first.j + 1,
+                second.i + 1,
+                second.j + 1
+            ),
+            Self::RaggedRow {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {} has {} characters instead of {}",
+                row + 1,
+                found,
+                expected
+            ),
+        }
+    }
+}
+
+impl Error for GridParseError {}
+
+/// A parsed grid, along with the positions of its markers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedGrid<T> {
+    pub grid: Grid<T>,
+    markers: HashMap<char, Index2D<usize>>,
+}
+
+impl<T> ParsedGrid<T> {
+    /// Returns the position of a marker, if it appeared in the grid.
+    pub fn marker(&self, ch: char) -> Option<Index2D<usize>> {
+        self.markers.get(&ch).copied()
+    }
+}
+
+/// Parses grids of [`CharCell`]s, pulling out markers that appear at most once, like the start
+/// `S` and end `E` of a maze, into named positions.
+#[derive(Debug, Clone)]
+pub struct GridParser<T> {
+    markers: Vec<(char, T)>,
+}
+
+impl<T> Default for GridParser<T> {
+    fn default() -> Self {
+        Self {
+            markers: Vec::new(),
+        }
+    }
+}
+
+impl<T: CharCell + Clone> GridParser<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the position of `marker`, which is then parsed as `cell`.
+    pub fn marker(mut self, marker: char, cell: T) -> Self {
+        self.markers.push((marker, cell));
+        self
+    }
+
+    pub fn parse(&self, text: &str) -> Result<ParsedGrid<T>, GridParseError> {
+        let mut markers = HashMap::new();
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+
+        for (i, line) in text.lines().enumerate() {
+            let mut found = 0;
+
+            for (j, ch) in line.chars().enumerate() {
+                let pos = Index2D::new(i, j);
+
+                let cell = match self.markers.iter().find(|(marker, _)| *marker == ch) {
+                    Some((_, cell)) => {
+                        if let Some(&first) = markers.get(&ch) {
+                            return Err(GridParseError::RepeatedMarker {
+                                ch,
+                                first,
+                                second: pos,
+                            });
+                        }
+
+                        markers.insert(ch, pos);
+                        cell.clone()
+                    }
+                    None => T::from_char(ch).ok_or(GridParseError::UnknownChar { ch, pos })?,
+                };
+
+                cells.push(cell);
+                found += 1;
+            }
+
+            let expected = *width.get_or_insert(found);
+            if found != expected {
+                return Err(GridParseError::RaggedRow {
+                    row: i,
+                    expected,
+                    found,
+                });
+            }
+
+            height += 1;
+        }
+
+        Ok(ParsedGrid {
+            grid: Grid::from_vec(height, width.unwrap_or(0), cells),
+            markers,
+        })
+    }
+}
+
+impl<T> Index<Index2D<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Index2D<usize>) -> &Self::Output {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("({}, {}) is out of the grid", pos.i, pos.j))
+    }
+}
+
+impl<T> IndexMut<Index2D<usize>> for Grid<T> {
+    fn index_mut(&mut self, pos: Index2D<usize>) -> &mut Self::Output {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("({}, {}) is out of the grid", pos.i, pos.j))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grid_works() {
+        let mut grid = Grid::parse("#.#\n.S.\n", |c| c);
+
+        assert_eq!((grid.height(), grid.width()), (2, 3));
+        assert_eq!(grid.get(Index2D::new(1, 1)), Some(&'S'));
+        assert_eq!(grid.get(Index2D::new(2, 0)), None);
+        assert_eq!(grid.find(&'S'), Some(Index2D::new(1, 1)));
+        assert_eq!(
+            grid.find_all(&'#').collect::<Vec<_>>(),
+            [Index2D::new(0, 0), Index2D::new(0, 2)]
+        );
+        assert_eq!(grid.row(1), ['.', 'S', '.']);
+        assert_eq!(grid.column(2).collect::<String>(), "#.");
+
+        grid[Index2D::new(1, 1)] = '.';
+        assert_eq!(grid.to_string(), "#.#\n...\n");
+        assert_eq!(grid.to_frame(|&c| c).to_string(), "#.#\n...\n");
+
+        assert_eq!(grid.get_wrapping(Index2D::new(-2, 5)), &'#');
+        assert_eq!(
+            grid.neighbors_wrapping(Index2D::new(0, 0))
+                .collect::<Vec<_>>(),
+            [
+                Index2D::new(1, 0),
+                Index2D::new(0, 1),
+                Index2D::new(1, 0),
+                Index2D::new(0, 2),
+            ]
+        );
+    }
+
+    crate::char_cell! {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        enum Tile {
+            Wall = '#',
+            Empty = '.',
+        }
+    }
+
+    #[test]
+    fn cells_are_parsed() {
+        let parsed = GridParser::new()
+            .marker('S', Tile::Empty)
+            .marker('E', Tile::Empty)
+            .parse("#S.\n.E#\n")
+            .unwrap();
+
+        assert_eq!(parsed.marker('S'), Some(Index2D::new(0, 1)));
+        assert_eq!(parsed.marker('E'), Some(Index2D::new(1, 1)));
+        assert_eq!(parsed.grid[Index2D::new(0, 1)], Tile::Empty);
+        assert_eq!(parsed.grid.to_string(), "#..\n..#\n");
+
+        assert_eq!(
+            Grid::<Tile>::parse_cells("#.\n.x\n"),
+            Err(GridParseError::UnknownChar {
+                ch: 'x',
+                pos: Index2D::new(1, 1)
+            })
+        );
+        assert_eq!(
+            GridParser::new()
+                .marker('S', Tile::Empty)
+                .parse("S.S\n")
+                .unwrap_err()
+                .to_string(),
+            "'S' appears at line 1, column 1 and again at line 1, column 3"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "rows differ in length")]
+    fn ragged_rows_panic() {
+        Grid::parse("##\n#\n", |c| c);
+    }
+}
@@ -0,0 +1,394 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+use super::{Index2D, SparseGrid};
+
+/// A cell of a hexagonal grid in axial coordinates. The third cube coordinate is implied, as
+/// `q + r + s == 0`.
+///
+/// The same coordinates work for both layouts; only the names of the directions change. With
+/// pointy tops, `q` grows to the east and `r` to the south-east. With flat tops, `q` grows to the
+/// south-east and `r` to the south.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Hex {
+    pub q: isize,
+    pub r: isize,
+}
+
+/// The six axial offsets, counter-clockwise from the east.
+const HEX_OFFSETS: [Hex; 6] = [
+    Hex::new(1, 0),
+    Hex::new(1, -1),
+    Hex::new(0, -1),
+    Hex::new(-1, 0),
+    Hex::new(-1, 1),
+    Hex::new(0, 1),
+];
+
+impl Hex {
+    pub const fn new(q: isize, r: isize) -> Self {
+        Self { q, r }
+    }
+
+    /// Creates a hex from cube coordinates, or returns `None` if they don't add up to 0.
+    pub fn from_cube(q: isize, r: isize, s: isize) -> Option<Self> {
+        (q + r + s == 0).then_some(Self::new(q, r))
+    }
+
+    pub fn s(&self) -> isize {
+        -self.q - self.r
+    }
+
+    pub fn to_cube(self) -> (isize, isize, isize) {
+        (self.q, self.r, self.s())
+    }
+
+    pub fn neighbor<D: HexDirection>(self, direction: D) -> Self {
+        self + direction.offset()
+    }
+
+    /// Returns the 6 neighbors, counter-clockwise from the east (pointy-top) or south-east
+    /// (flat-top).
+    pub fn neighbors(self) -> impl Iterator<Item = Self> {
+        HEX_OFFSETS.into_iter().map(move |offset| self + offset)
+    }
+
+    /// The number of steps from the origin.
+    pub fn length(self) -> isize {
+        (self.q.abs() + self.r.abs() + self.s().abs()) / 2
+    }
+
+    /// The number of steps between two hexes.
+    pub fn distance(self, other: Self) -> isize {
+        (self - other).length()
+    }
+
+    /// Returns the hexes at exactly `radius` steps, going around counter-clockwise from the
+    /// south-west.
+    pub fn ring(self, radius: usize) -> Vec<Self> {
+        if radius == 0 {
+            return vec![self];
+        }
+
+        let mut hexes = Vec::with_capacity(6 * radius);
+        let mut hex = self + HEX_OFFSETS[4] * radius as isize;
+
+        for offset in HEX_OFFSETS {
+            for _ in 0..radius {
+                hexes.push(hex);
+                hex += offset;
+            }
+        }
+
+        hexes
+    }
+
+    /// Returns the hexes within `radius` steps, ring by ring from the center.
+    pub fn spiral(self, radius: usize) -> Vec<Self> {
+        (0..=radius).flat_map(|radius| self.ring(radius)).collect()
+    }
+
+    /// Rotates by 60° clockwise around a center, as seen on screen with `r` growing downwards.
+    pub fn rotated_clockwise(self, center: Self) -> Self {
+        let (_, r, s) = (self - center).to_cube();
+        center + Self::new(-r, -s)
+    }
+
+    /// Rotates by a number of sixth turns around a center, clockwise if positive.
+    pub fn rotated(self, center: Self, sixth_turns: i32) -> Self {
+        (0..sixth_turns.rem_euclid(6)).fold(self, |hex, _| hex.rotated_clockwise(center))
+    }
+
+    /// Converts to a position on a rectangular grid where every other column (pointy-top) or row
+    /// (flat-top) is left out, so that hexes can be stored in a [`SparseGrid`] and rendered.
+    pub fn to_index(self, layout: HexLayout) -> Index2D<isize> {
+        match layout {
+            HexLayout::Pointy => Index2D::new(self.r, 2 * self.q + self.r),
+            HexLayout::Flat => Index2D::new(2 * self.r + self.q, self.q),
+        }
+    }
+
+    /// Converts back from [`Hex::to_index`], or returns `None` for a position that is left out.
+    pub fn from_index(pos: Index2D<isize>, layout: HexLayout) -> Option<Self> {
+        let (doubled, other) = match layout {
+            HexLayout::Pointy => (pos.j, pos.i),
+            HexLayout::Flat => (pos.i, pos.j),
+        };
+
+        if (doubled - other).rem_euclid(2) != 0 {
+            return None;
+        }
+
+        Some(match layout {
+            HexLayout::Pointy => Self::new((pos.j - pos.i) / 2, pos.i),
+            HexLayout::Flat => Self::new(pos.j, (pos.i - pos.j) / 2),
+        })
+    }
+}
+
+/// Whether the hexagons have a vertex (pointy-top) or an edge (flat-top) at the top.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HexLayout {
+    Pointy,
+    Flat,
+}
+
+impl<T> SparseGrid<T> {
+    /// Collects hexes into a sparse grid laid out with [`Hex::to_index`], ready to be rendered.
+    pub fn from_hexes<I: IntoIterator<Item = (Hex, T)>>(hexes: I, layout: HexLayout) -> Self {
+        let mut grid = Self::new();
+
+        for (hex, value) in hexes {
+            grid.insert(hex.to_index(layout), value);
+        }
+
+        grid
+    }
+}
+
+/// The six directions of a hex grid layout, which can be parsed from their lowercase compass
+/// names.
+pub trait HexDirection: Copy + Sized + 'static {
+    /// Every direction, counter-clockwise.
+    const ALL: [Self; 6];
+
+    fn name(&self) -> &'static str;
+
+    fn offset(&self) -> Hex;
+
+    fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|direction| direction.name() == name)
+    }
+}
+
+/// A direction on a hex grid with a vertex at the top, where rows are horizontal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PointyHexDirection {
+    East,
+    NorthEast,
+    NorthWest,
+    West,
+    SouthWest,
+    SouthEast,
+}
+
+impl HexDirection for PointyHexDirection {
+    const ALL: [Self; 6] = [
+        Self::East,
+        Self::NorthEast,
+        Self::NorthWest,
+        Self::West,
+        Self::SouthWest,
+        Self::SouthEast,
+    ];
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::East => "e",
+            Self::NorthEast => "ne",
+            Self::NorthWest => "nw",
+            Self::West => "w",
+            Self::SouthWest => "sw",
+            Self::SouthEast => "se",
+        }
+    }
+
+    fn offset(&self) -> Hex {
+        HEX_OFFSETS[*self as usize]
+    }
+}
+
+/// A direction on a hex grid with an edge at the top, where columns are vertical.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FlatHexDirection {
+    SouthEast,
+    NorthEast,
+    North,
+    NorthWest,
+    SouthWest,
+    South,
+}
+
+impl HexDirection for FlatHexDirection {
+    const ALL: [Self; 6] = [
+        Self::SouthEast,
+        Self::NorthEast,
+        Self::North,
+        Self::NorthWest,
+        Self::SouthWest,
+        Self::South,
+    ];
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::SouthEast => "se",
+            Self::NorthEast => "ne",
+            Self::North => "n",
+            Self::NorthWest => "nw",
+            Self::SouthWest => "sw",
+            Self::South => "s",
+        }
+    }
+
+    fn offset(&self) -> Hex {
+        HEX_OFFSETS[*self as usize]
+    }
+}
+
+/// A hex path contained something other than a direction, at the given byte offset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HexPathError {
+    pub position: usize,
+}
+
+impl Display for HexPathError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "unknown hex direction at character {}",
+            self.position + 1
+        )
+    }
+}
+
+impl Error for HexPathError {}
+
+/// Parses a path of hex directions, either separated by commas or whitespace as in `ne,se,nw`,
+/// or written back to back as in `esenee`.
+pub fn parse_hex_path<D: HexDirection>(path: &str) -> Result<Vec<D>, HexPathError> {
+    let mut directions = Vec::new();
+    let mut position = 0;
+
+    while position < path.len() {
+        let rest = &path[position..];
+
+        if rest.starts_with(|c: char| c == ',' || c.is_whitespace()) {
+            position += rest.chars().next().map_or(1, char::len_utf8);
+            continue;
+        }
+
+        // The longest name wins, so that `ne` isn't read as `n` followed by `e`.
+        let (direction, len) = [2, 1]
+            .into_iter()
+            .filter_map(|len| Some((D::from_name(rest.get(..len)?)?, len)))
+            .next()
+            .ok_or(HexPathError { position })?;
+
+        directions.push(direction);
+        position += len;
+    }
+
+    Ok(directions)
+}
+
+impl Add for Hex {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Self::new(self.q + other.q, self.r + other.r)
+    }
+}
+
+impl AddAssign for Hex {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Hex {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Self::new(self.q - other.q, self.r - other.r)
+    }
+}
+
+impl SubAssign for Hex {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Hex {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.q, -self.r)
+    }
+}
+
+impl Mul<isize> for Hex {
+    type Output = Self;
+
+    fn mul(self, scalar: isize) -> Self::Output {
+        Self::new(self.q * scalar, self.r * scalar)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hex_distances_and_rings_work() {
+        let origin = Hex::default();
+        let hex = Hex::from_cube(3, -1, -2).unwrap();
+        assert_eq!(Hex::from_cube(1, 1, 1), None);
+        assert_eq!(hex.distance(origin), 3);
+
+        let ring = origin.ring(2);
+        assert_eq!(ring.len(), 12);
+        assert!(ring.iter().all(|hex| hex.length() == 2));
+        assert_eq!(origin.spiral(2).len(), 19);
+        let mut neighbors = origin.neighbors().collect::<Vec<_>>();
+        let mut ring = origin.ring(1);
+        neighbors.sort_unstable();
+        ring.sort_unstable();
+        assert_eq!(neighbors, ring);
+
+        let east = Hex::new(1, 0);
+        let center = Hex::new(5, 5);
+        assert_eq!(
+            (east + center).rotated_clockwise(center),
+            center.neighbor(PointyHexDirection::SouthEast)
+        );
+        assert_eq!((east + center).rotated(center, -6), east + center);
+        assert_eq!(hex.rotated(origin, 3), -hex);
+    }
+
+    #[test]
+    fn hex_paths_are_parsed() {
+        let path = parse_hex_path::<FlatHexDirection>("ne,ne,s,s").unwrap();
+        let end = path.iter().fold(Hex::default(), |hex, &d| hex.neighbor(d));
+        assert_eq!(end.length(), 2);
+
+        let path = parse_hex_path::<PointyHexDirection>("nwwswee").unwrap();
+        let end = path.iter().fold(Hex::default(), |hex, &d| hex.neighbor(d));
+        assert_eq!(end, Hex::default());
+
+        assert_eq!(
+            parse_hex_path::<FlatHexDirection>("n,e"),
+            Err(HexPathError { position: 2 })
+        );
+    }
+
+    #[test]
+    fn hexes_are_rendered() {
+        for layout in [HexLayout::Pointy, HexLayout::Flat] {
+            for hex in Hex::new(1, -2).spiral(3) {
+                assert_eq!(Hex::from_index(hex.to_index(layout), layout), Some(hex));
+            }
+        }
+
+        let grid = SparseGrid::from_hexes(
+            Hex::default().spiral(1).into_iter().map(|hex| (hex, '#')),
+            HexLayout::Pointy,
+        );
+        assert_eq!(grid.to_text(' ', |&c| c), " # # \n# # #\n # # \n");
+    }
+}
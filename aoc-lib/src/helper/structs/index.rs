@@ -1,5 +1,9 @@
 use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
+use num::{CheckedAdd, CheckedSub, One};
+
+use super::{Direction, DIRECTIONS};
+
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Index2D<T> {
     pub i: T,
@@ -12,6 +16,98 @@ impl<T> Index2D<T> {
     }
 }
 
+/// Returns `|a - b|` without going through negative values, so that it works for unsigned types.
+fn abs_diff<T: PartialOrd + Sub<Output = T>>(a: T, b: T) -> T {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+impl<T: Copy + CheckedAdd + CheckedSub + One> Index2D<T> {
+    /// Moves one step in a direction, where up decreases `i` and left decreases `j`. Returns
+    /// `None` if the position would overflow, e.g. when going up from row 0 of a `usize` index.
+    pub fn step(self, direction: Direction) -> Option<Self> {
+        let one = T::one();
+
+        match direction {
+            Direction::Up => self.i.checked_sub(&one).map(|i| Self::new(i, self.j)),
+            Direction::Right => self.j.checked_add(&one).map(|j| Self::new(self.i, j)),
+            Direction::Down => self.i.checked_add(&one).map(|i| Self::new(i, self.j)),
+            Direction::Left => self.j.checked_sub(&one).map(|j| Self::new(self.i, j)),
+        }
+    }
+
+    /// Returns the 4 orthogonal neighbors, up, right, down then left, skipping those that would
+    /// overflow.
+    pub fn neighbors_4(self) -> impl Iterator<Item = Self> {
+        DIRECTIONS
+            .into_iter()
+            .filter_map(move |direction| self.step(direction))
+    }
+
+    /// Returns the 8 orthogonal and diagonal neighbors, clockwise from the one above, skipping
+    /// those that would overflow.
+    pub fn neighbors_8(self) -> impl Iterator<Item = Self> {
+        DIRECTIONS.into_iter().flat_map(move |direction| {
+            let orthogonal = self.step(direction);
+            let diagonal = orthogonal.and_then(|pos| pos.step(direction.rotated_clockwise()));
+
+            [orthogonal, diagonal].into_iter().flatten()
+        })
+    }
+}
+
+impl<T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T>> Index2D<T> {
+    /// The number of orthogonal steps between two positions.
+    pub fn manhattan_distance(self, other: Self) -> T {
+        abs_diff(self.i, other.i) + abs_diff(self.j, other.j)
+    }
+
+    /// The number of steps between two positions when diagonal steps are allowed.
+    pub fn chebyshev_distance(self, other: Self) -> T {
+        let di = abs_diff(self.i, other.i);
+        let dj = abs_diff(self.j, other.j);
+
+        if di > dj {
+            di
+        } else {
+            dj
+        }
+    }
+}
+
+impl Index2D<usize> {
+    /// Returns the orthogonal neighbors that lie within a grid of `size` rows and columns.
+    pub fn neighbors_within(self, size: Self) -> impl Iterator<Item = Self> {
+        self.neighbors_4()
+            .filter(move |pos| pos.i < size.i && pos.j < size.j)
+    }
+
+    /// Converts to a signed index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a coordinate doesn't fit in an `isize`.
+    pub fn to_signed(self) -> Index2D<isize> {
+        Index2D::new(
+            isize::try_from(self.i).expect("index is too large to be signed"),
+            isize::try_from(self.j).expect("index is too large to be signed"),
+        )
+    }
+}
+
+impl Index2D<isize> {
+    /// Converts to an unsigned index, or returns `None` if a coordinate is negative.
+    pub fn to_unsigned(self) -> Option<Index2D<usize>> {
+        Some(Index2D::new(
+            usize::try_from(self.i).ok()?,
+            usize::try_from(self.j).ok()?,
+        ))
+    }
+}
+
 impl<T: Neg<Output = T>> Neg for Index2D<T> {
     type Output = Self;
 
@@ -94,3 +190,46 @@ impl<T: DivAssign + Copy> DivAssign<T> for Index2D<T> {
         self.j /= rhs;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn neighbors_work() {
+        let corner = Index2D::<usize>::new(0, 0);
+
+        assert_eq!(corner.step(Direction::Up), None);
+        assert_eq!(corner.step(Direction::Down), Some(Index2D::new(1, 0)));
+        assert_eq!(
+            corner.neighbors_4().collect::<Vec<_>>(),
+            [Index2D::new(0, 1), Index2D::new(1, 0)]
+        );
+        assert_eq!(
+            corner.neighbors_8().collect::<Vec<_>>(),
+            [Index2D::new(0, 1), Index2D::new(1, 1), Index2D::new(1, 0)]
+        );
+        assert_eq!(Index2D::new(0isize, 0).neighbors_8().count(), 8);
+        assert_eq!(
+            Index2D::<usize>::new(1, 1)
+                .neighbors_within(Index2D::new(2, 2))
+                .collect::<Vec<_>>(),
+            [Index2D::new(0, 1), Index2D::new(1, 0)]
+        );
+    }
+
+    #[test]
+    fn distances_work() {
+        let a = Index2D::<usize>::new(1, 5);
+        let b = Index2D::new(4, 3);
+
+        assert_eq!(a.manhattan_distance(b), 5);
+        assert_eq!(a.chebyshev_distance(b), 3);
+        assert_eq!(a.to_signed(), Index2D::new(1, 5));
+        assert_eq!(Index2D::new(-1isize, 2).to_unsigned(), None);
+        assert_eq!(
+            Index2D::new(1isize, 2).to_unsigned(),
+            Some(Index2D::new(1, 2))
+        );
+    }
+}
@@ -1,9 +1,23 @@
+mod char_cell;
 mod direction;
+mod grid;
+mod hex;
 mod index;
 mod priority_queue;
+mod segment;
+mod sparse_grid;
+mod torus;
 mod vector;
+mod vector_n;
 
+pub use char_cell::*;
 pub use direction::*;
+pub use grid::*;
+pub use hex::*;
 pub use index::*;
 pub use priority_queue::*;
+pub use segment::*;
+pub use sparse_grid::*;
+pub use torus::*;
 pub use vector::*;
+pub use vector_n::*;
@@ -0,0 +1,385 @@
+use std::{cmp::Ordering, collections::BTreeMap};
+
+use num::{rational::Ratio, Integer, Signed};
+
+use super::{Direction, Vector2D};
+
+/// How two segments meet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Intersection<T> {
+    None,
+    Point(Vector2D<T>),
+    /// The segments are collinear and share the part between these two points, given in the
+    /// direction of the first segment.
+    Overlap(Vector2D<T>, Vector2D<T>),
+}
+
+impl<T> Intersection<T> {
+    pub fn map<U, F: FnMut(T) -> U>(self, mut f: F) -> Intersection<U> {
+        let mut map = |v: Vector2D<T>| Vector2D::new(f(v.x), f(v.y));
+
+        match self {
+            Self::None => Intersection::None,
+            Self::Point(p) => Intersection::Point(map(p)),
+            Self::Overlap(a, b) => Intersection::Overlap(map(a), map(b)),
+        }
+    }
+}
+
+/// A segment between two integer points, both ends included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Segment<T> {
+    pub start: Vector2D<T>,
+    pub end: Vector2D<T>,
+}
+
+impl<T: Integer + Signed + Copy> Segment<T> {
+    pub fn new(start: Vector2D<T>, end: Vector2D<T>) -> Self {
+        Self { start, end }
+    }
+
+    pub fn delta(&self) -> Vector2D<T> {
+        self.end - self.start
+    }
+
+    pub fn contains(&self, point: Vector2D<T>) -> bool {
+        let within = |p: T, a: T, b: T| a.min(b) <= p && p <= a.max(b);
+
+        (point - self.start).cross(self.delta()).is_zero()
+            && within(point.x, self.start.x, self.end.x)
+            && within(point.y, self.start.y, self.end.y)
+    }
+
+    /// Finds where two segments meet. A crossing point can fall between integer coordinates, so
+    /// it is given as exact fractions.
+    pub fn intersection(&self, other: &Self) -> Intersection<Ratio<T>> {
+        let exact = |p: Vector2D<T>| Intersection::Point(p).map(Ratio::from_integer);
+
+        let d1 = self.delta();
+        let d2 = other.delta();
+        let w = other.start - self.start;
+
+        if d1.x.is_zero() && d1.y.is_zero() {
+            return match other.contains(self.start) {
+                true => exact(self.start),
+                false => Intersection::None,
+            };
+        }
+
+        if d2.x.is_zero() && d2.y.is_zero() {
+            return match self.contains(other.start) {
+                true => exact(other.start),
+                false => Intersection::None,
+            };
+        }
+
+        let denominator = d1.cross(d2);
+
+        if denominator.is_zero() {
+            if !w.cross(d1).is_zero() {
+                // Parallel, on different lines.
+                return Intersection::None;
+            }
+
+            // Collinear: compare the positions along the first segment, scaled by its squared
+            // length so that they stay integers. The overlap always ends on an endpoint.
+            let length = d1.dot(d1);
+            let ends = [
+                (T::zero(), self.start),
+                (length, self.end),
+                (w.dot(d1), other.start),
+                ((other.end - self.start).dot(d1), other.end),
+            ];
+            let (other_min, other_max) = match ends[2].0 <= ends[3].0 {
+                true => (ends[2], ends[3]),
+                false => (ends[3], ends[2]),
+            };
+            let low = if other_min.0 > T::zero() {
+                other_min
+            } else {
+                ends[0]
+            };
+            let high = if other_max.0 < length {
+                other_max
+            } else {
+                ends[1]
+            };
+
+            return match low.0.cmp(&high.0) {
+                Ordering::Less => Intersection::Overlap(low.1, high.1).map(Ratio::from_integer),
+                Ordering::Equal => exact(low.1),
+                Ordering::Greater => Intersection::None,
+            };
+        }
+
+        // The crossing is at `start + d1 * t` and `other.start + d2 * u`, with `t` and `u`
+        // between 0 and 1.
+        let (mut t, mut u, mut denominator) = (w.cross(d2), w.cross(d1), denominator);
+        if denominator.is_negative() {
+            (t, u, denominator) = (-t, -u, -denominator);
+        }
+
+        if t.is_negative() || u.is_negative() || t > denominator || u > denominator {
+            return Intersection::None;
+        }
+
+        let coordinate =
+            |start: T, delta: T| Ratio::new(start * denominator + delta * t, denominator);
+        Intersection::Point(Vector2D::new(
+            coordinate(self.start.x, d1.x),
+            coordinate(self.start.y, d1.y),
+        ))
+    }
+}
+
+/// A horizontal or vertical segment, such as a stretch of a wire on a square grid. Crossings of
+/// these always have integer coordinates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AxisSegment<T>(Segment<T>);
+
+impl<T: Integer + Signed + Copy> AxisSegment<T> {
+    /// Creates a segment, or returns `None` if it is neither horizontal nor vertical.
+    pub fn new(start: Vector2D<T>, end: Vector2D<T>) -> Option<Self> {
+        (start.x == end.x || start.y == end.y).then_some(Self(Segment::new(start, end)))
+    }
+
+    /// Follows moves from a starting point, with `y` growing downwards as in puzzle maps, and
+    /// returns the segments of the path in order.
+    pub fn path<I: IntoIterator<Item = (Direction, T)>>(start: Vector2D<T>, moves: I) -> Vec<Self> {
+        let mut position = start;
+
+        moves
+            .into_iter()
+            .map(|(direction, distance)| {
+                let end = position + direction.vector() * distance;
+                let segment = Self(Segment::new(position, end));
+                position = end;
+                segment
+            })
+            .collect()
+    }
+
+    pub fn segment(&self) -> Segment<T> {
+        self.0
+    }
+
+    pub fn start(&self) -> Vector2D<T> {
+        self.0.start
+    }
+
+    pub fn end(&self) -> Vector2D<T> {
+        self.0.end
+    }
+
+    /// Whether the segment runs along the `x` axis. Segments of a single point count as
+    /// horizontal.
+    pub fn is_horizontal(&self) -> bool {
+        self.0.start.y == self.0.end.y
+    }
+
+    /// The number of steps from one end to the other.
+    pub fn len(&self) -> T {
+        self.0.delta().manhattan_length()
+    }
+
+    pub fn contains(&self, point: Vector2D<T>) -> bool {
+        self.0.contains(point)
+    }
+
+    pub fn intersection(&self, other: &Self) -> Intersection<T> {
+        self.0.intersection(&other.0).map(|c| c.to_integer())
+    }
+}
+
+/// The number of steps along a path until it first reaches a point, or `None` if it never does.
+pub fn distance_along<T: Integer + Signed + Copy>(
+    path: &[AxisSegment<T>],
+    point: Vector2D<T>,
+) -> Option<T> {
+    let mut distance = T::zero();
+
+    for segment in path {
+        if segment.contains(point) {
+            return Some(distance + (point - segment.start()).manhattan_length());
+        }
+
+        distance = distance + segment.len();
+    }
+
+    None
+}
+
+/// A crossing found by [`sweep_crossings`], with the indices of the two segments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Crossing<T> {
+    pub horizontal: usize,
+    pub vertical: usize,
+    pub point: Vector2D<T>,
+}
+
+/// Finds every point where a horizontal segment meets a vertical one, by sweeping a line across
+/// the `x` axis. This takes O((n + k) log n) time for n segments and k crossings, instead of
+/// comparing every pair. Parallel segments that overlap are not reported.
+pub fn sweep_crossings<T: Integer + Signed + Copy>(
+    segments: &[AxisSegment<T>],
+) -> Vec<Crossing<T>> {
+    // Horizontal segments start before, and end after, the vertical ones at the same `x`, so
+    // that crossings at their ends are found.
+    const START: u8 = 0;
+    const QUERY: u8 = 1;
+    const END: u8 = 2;
+
+    let mut events = Vec::with_capacity(segments.len() * 2);
+
+    for (index, segment) in segments.iter().enumerate() {
+        let (start, end) = (segment.start(), segment.end());
+
+        if segment.is_horizontal() {
+            events.push((start.x.min(end.x), START, index));
+            events.push((start.x.max(end.x), END, index));
+        } else {
+            events.push((start.x, QUERY, index));
+        }
+    }
+
+    events.sort_unstable_by_key(|&(x, kind, _)| (x, kind));
+
+    let mut active = BTreeMap::<T, Vec<usize>>::new();
+    let mut crossings = Vec::new();
+
+    for (x, kind, index) in events {
+        let segment = segments[index];
+
+        match kind {
+            START => active.entry(segment.start().y).or_default().push(index),
+            END => {
+                let y = segment.start().y;
+                let indices = active.get_mut(&y).expect("segment ends before it starts");
+                indices.retain(|&i| i != index);
+
+                if indices.is_empty() {
+                    active.remove(&y);
+                }
+            }
+            _ => {
+                let (y1, y2) = (segment.start().y, segment.end().y);
+
+                for (&y, indices) in active.range(y1.min(y2)..=y1.max(y2)) {
+                    crossings.extend(indices.iter().map(|&horizontal| Crossing {
+                        horizontal,
+                        vertical: index,
+                        point: Vector2D::new(x, y),
+                    }));
+                }
+            }
+        }
+    }
+
+    crossings
+}
+
+#[cfg(test)]
+mod tests {
+    use fastrand::Rng;
+
+    use super::*;
+
+    fn segment(a: (i64, i64), b: (i64, i64)) -> Segment<i64> {
+        Segment::new(Vector2D::new(a.0, a.1), Vector2D::new(b.0, b.1))
+    }
+
+    #[test]
+    fn segments_intersect_exactly() {
+        let point = |x: (i64, i64), y: (i64, i64)| {
+            Intersection::Point(Vector2D::new(Ratio::new(x.0, x.1), Ratio::new(y.0, y.1)))
+        };
+
+        let a = segment((0, 0), (4, 4));
+        assert_eq!(
+            a.intersection(&segment((0, 4), (4, 0))),
+            point((2, 1), (2, 1))
+        );
+        assert_eq!(
+            a.intersection(&segment((0, 1), (1, 1))),
+            point((1, 1), (1, 1))
+        );
+        assert_eq!(
+            segment((0, 0), (1, 2)).intersection(&segment((1, 0), (0, 1))),
+            point((1, 3), (2, 3))
+        );
+        assert_eq!(a.intersection(&segment((0, 1), (3, 4))), Intersection::None);
+        assert_eq!(a.intersection(&segment((5, 5), (6, 6))), Intersection::None);
+        assert_eq!(
+            a.intersection(&segment((6, 6), (2, 2))),
+            Intersection::Overlap(Vector2D::new(2, 2), Vector2D::new(4, 4)).map(Ratio::from)
+        );
+        assert_eq!(
+            a.intersection(&segment((4, 4), (6, 6))),
+            point((4, 1), (4, 1))
+        );
+        assert_eq!(
+            a.intersection(&segment((3, 3), (3, 3))),
+            point((3, 1), (3, 1))
+        );
+    }
+
+    #[test]
+    fn sweep_finds_every_crossing() {
+        let mut rng = Rng::with_seed(0);
+
+        for _ in 0..100 {
+            let segments = (0..20)
+                .map(|_| {
+                    let start = Vector2D::new(rng.i64(0..20), rng.i64(0..20));
+                    let length = rng.i64(-10..10);
+                    let end = match rng.bool() {
+                        true => start + Vector2D::new(length, 0),
+                        false => start + Vector2D::new(0, length),
+                    };
+
+                    AxisSegment::new(start, end).unwrap()
+                })
+                .collect::<Vec<_>>();
+
+            let mut expected = Vec::new();
+            for (h, horizontal) in segments.iter().enumerate() {
+                for (v, vertical) in segments.iter().enumerate() {
+                    if !horizontal.is_horizontal() || vertical.is_horizontal() {
+                        continue;
+                    }
+
+                    if let Intersection::Point(point) = horizontal.intersection(vertical) {
+                        expected.push(Crossing {
+                            horizontal: h,
+                            vertical: v,
+                            point,
+                        });
+                    }
+                }
+            }
+
+            let mut found = sweep_crossings(&segments);
+            let key = |c: &Crossing<i64>| (c.horizontal, c.vertical);
+            found.sort_unstable_by_key(key);
+            expected.sort_unstable_by_key(key);
+            assert_eq!(found, expected);
+        }
+    }
+
+    #[test]
+    fn distances_follow_the_path() {
+        let path = AxisSegment::path(
+            Vector2D::new(0, 0),
+            [
+                (Direction::Right, 8),
+                (Direction::Up, 5),
+                (Direction::Left, 5),
+            ],
+        );
+
+        assert_eq!(path[1].end(), Vector2D::new(8, -5));
+        assert_eq!(distance_along(&path, Vector2D::new(8, -2)), Some(10));
+        assert_eq!(distance_along(&path, Vector2D::new(6, -5)), Some(15));
+        assert_eq!(distance_along(&path, Vector2D::new(1, 1)), None);
+    }
+}
@@ -0,0 +1,291 @@
+use std::{collections::HashMap, hash::Hash};
+
+use crate::visual::{Cell, Frame};
+
+use super::{Grid, Index2D, Vector2D};
+
+/// A signed position that a [`SparseGrid`] can be keyed by, made of a row and a column.
+pub trait SparsePosition: Copy + Eq + Hash {
+    fn row(&self) -> isize;
+
+    fn column(&self) -> isize;
+
+    fn from_row_column(row: isize, column: isize) -> Self;
+}
+
+impl SparsePosition for Index2D<isize> {
+    fn row(&self) -> isize {
+        self.i
+    }
+
+    fn column(&self) -> isize {
+        self.j
+    }
+
+    fn from_row_column(row: isize, column: isize) -> Self {
+        Self::new(row, column)
+    }
+}
+
+/// The row is `y`, growing downwards as in puzzle maps, and the column is `x`.
+impl SparsePosition for Vector2D<isize> {
+    fn row(&self) -> isize {
+        self.y
+    }
+
+    fn column(&self) -> isize {
+        self.x
+    }
+
+    fn from_row_column(row: isize, column: isize) -> Self {
+        Self::new(column, row)
+    }
+}
+
+/// An unbounded grid that only stores the cells that were set, with signed positions. It keeps
+/// track of the smallest box containing all of them, so that it can be rendered.
+///
+/// Positions are [`Index2D`]s by default, but can be any [`SparsePosition`], such as the
+/// [`Vector2D`]s that coordinates in `x` and `y` are usually kept in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T, P: SparsePosition = Index2D<isize>> {
+    cells: HashMap<P, T>,
+    /// The top left and bottom right corners of the bounding box, both inclusive.
+    bounds: Option<(P, P)>,
+}
+
+impl<T, P: SparsePosition> Default for SparseGrid<T, P> {
+    fn default() -> Self {
+        Self {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T, P: SparsePosition> SparseGrid<T, P> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, pos: P) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    pub fn get(&self, pos: P) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+
+    pub fn get_mut(&mut self, pos: P) -> Option<&mut T> {
+        self.cells.get_mut(&pos)
+    }
+
+    fn extend_bounds(&mut self, pos: P) {
+        self.bounds = Some(match self.bounds {
+            Some((min, max)) => (
+                P::from_row_column(min.row().min(pos.row()), min.column().min(pos.column())),
+                P::from_row_column(max.row().max(pos.row()), max.column().max(pos.column())),
+            ),
+            None => (pos, pos),
+        });
+    }
+
+    /// Sets a cell, returning its previous value.
+    pub fn insert(&mut self, pos: P, value: T) -> Option<T> {
+        self.extend_bounds(pos);
+        self.cells.insert(pos, value)
+    }
+
+    pub fn get_or_insert_with<F: FnOnce() -> T>(&mut self, pos: P, f: F) -> &mut T {
+        self.extend_bounds(pos);
+        self.cells.entry(pos).or_insert_with(f)
+    }
+
+    /// Clears a cell, returning its value. The bounding box shrinks if the cell was on its edge.
+    pub fn remove(&mut self, pos: P) -> Option<T> {
+        let value = self.cells.remove(&pos)?;
+
+        if let Some((min, max)) = self.bounds {
+            if pos.row() == min.row()
+                || pos.row() == max.row()
+                || pos.column() == min.column()
+                || pos.column() == max.column()
+            {
+                self.bounds = None;
+                let positions = self.cells.keys().copied().collect::<Vec<_>>();
+
+                for pos in positions {
+                    self.extend_bounds(pos);
+                }
+            }
+        }
+
+        Some(value)
+    }
+
+    /// Returns the top left and bottom right corners of the smallest box containing every cell,
+    /// both inclusive, or `None` if the grid is empty.
+    pub fn bounds(&self) -> Option<(P, P)> {
+        self.bounds
+    }
+
+    /// Returns the cells in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (P, &T)> {
+        self.cells.iter().map(|(&pos, value)| (pos, value))
+    }
+
+    /// Returns the cells row by row, from the top left.
+    pub fn iter_rows(&self) -> impl Iterator<Item = (P, &T)> {
+        let mut cells = self.iter().collect::<Vec<_>>();
+        cells.sort_unstable_by_key(|&(pos, _)| (pos.row(), pos.column()));
+        cells.into_iter()
+    }
+
+    /// Renders the bounding box as text, with `empty` for the cells that aren't set.
+    pub fn to_text<F: FnMut(&T) -> char>(&self, empty: char, mut f: F) -> String {
+        let Some((min, max)) = self.bounds else {
+            return String::new();
+        };
+
+        let mut text = String::new();
+
+        for i in min.row()..=max.row() {
+            for j in min.column()..=max.column() {
+                text.push(self.get(P::from_row_column(i, j)).map_or(empty, &mut f));
+            }
+
+            text.push('\n');
+        }
+
+        text
+    }
+
+    /// Renders the bounding box as a frame for visualizations, with `empty` for the cells that
+    /// aren't set.
+    pub fn to_frame<C: Into<Cell>, F: FnMut(&T) -> C>(&self, empty: Cell, mut f: F) -> Frame {
+        let Some((min, max)) = self.bounds else {
+            return Frame::new(0, 0);
+        };
+
+        let (height, width) = size(min, max);
+        let mut frame = Frame::filled(height, width, empty);
+
+        for (pos, value) in self.iter() {
+            let (i, j) = offset(pos, min);
+            frame.set(i, j, f(value));
+        }
+
+        frame
+    }
+}
+
+/// The number of rows and columns of a bounding box.
+fn size<P: SparsePosition>(min: P, max: P) -> (usize, usize) {
+    let (i, j) = offset(max, min);
+    (i + 1, j + 1)
+}
+
+/// The row and column of a position within a bounding box, from its top left corner.
+fn offset<P: SparsePosition>(pos: P, min: P) -> (usize, usize) {
+    (
+        (pos.row() - min.row()) as usize,
+        (pos.column() - min.column()) as usize,
+    )
+}
+
+impl<T: Clone, P: SparsePosition> SparseGrid<T, P> {
+    /// Converts a dense grid, keeping the cells for which `keep` returns `true`. The top left
+    /// cell of the grid ends up at `origin`.
+    pub fn from_grid<F: FnMut(&T) -> bool>(grid: &Grid<T>, origin: P, mut keep: F) -> Self {
+        let mut sparse = Self::new();
+
+        for (pos, value) in grid.iter() {
+            if keep(value) {
+                let pos = P::from_row_column(
+                    origin.row() + pos.i as isize,
+                    origin.column() + pos.j as isize,
+                );
+                sparse.insert(pos, value.clone());
+            }
+        }
+
+        sparse
+    }
+
+    /// Converts the bounding box to a dense grid, with `empty` for the cells that aren't set.
+    /// Returns the grid along with the position of its top left cell.
+    pub fn to_grid(&self, empty: T) -> (Grid<T>, P) {
+        let Some((min, max)) = self.bounds else {
+            return (Grid::from_vec(0, 0, Vec::new()), P::from_row_column(0, 0));
+        };
+
+        let (height, width) = size(min, max);
+        let mut grid = Grid::filled(height, width, empty);
+
+        for (pos, value) in self.iter() {
+            let (i, j) = offset(pos, min);
+            grid[Index2D::new(i, j)] = value.clone();
+        }
+
+        (grid, min)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sparse_grid_works() {
+        let mut grid = SparseGrid::new();
+        grid.insert(Index2D::new(-1, 2), 'a');
+        grid.insert(Index2D::new(1, -1), 'b');
+        grid.insert(Index2D::new(0, 0), 'c');
+
+        assert_eq!(
+            grid.bounds(),
+            Some((Index2D::new(-1, -1), Index2D::new(1, 2)))
+        );
+        assert_eq!(grid.to_text('.', |&c| c), "...a\n.c..\nb...\n");
+        assert_eq!(grid.iter_rows().map(|(_, &c)| c).collect::<String>(), "acb");
+
+        grid.remove(Index2D::new(1, -1));
+        assert_eq!(
+            grid.bounds(),
+            Some((Index2D::new(-1, 0), Index2D::new(0, 2)))
+        );
+
+        let (dense, origin) = grid.to_grid('.');
+        assert_eq!(dense.to_string(), "..a\nc..\n");
+        assert_eq!(origin, Index2D::new(-1, 0));
+        assert_eq!(SparseGrid::from_grid(&dense, origin, |&c| c != '.'), grid);
+    }
+
+    #[test]
+    fn vectors_are_positions() {
+        let mut grid = SparseGrid::<char, Vector2D<isize>>::new();
+        grid.insert(Vector2D::new(2, -1), 'a');
+        grid.insert(Vector2D::new(0, 1), 'b');
+
+        assert_eq!(
+            grid.bounds(),
+            Some((Vector2D::new(0, -1), Vector2D::new(2, 1)))
+        );
+        assert_eq!(
+            grid.to_text('.', |&c| c),
+            "..a
+...
+b..
+"
+        );
+        assert_eq!(grid.to_grid('.').1, Vector2D::new(0, -1));
+    }
+}
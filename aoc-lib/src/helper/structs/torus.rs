@@ -0,0 +1,136 @@
+use num::{Integer, Signed};
+
+use super::{Index2D, Vector2D, DIRECTIONS};
+
+/// A rectangular space whose edges wrap around, so that leaving on one side comes back in on the
+/// other. Positions given to it can be anywhere; the ones it returns are always within
+/// `0..width` and `0..height`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Torus<T> {
+    size: Vector2D<T>,
+}
+
+/// The distance between two coordinates along an axis of length `size` that wraps around.
+fn wrapping_distance<T: Integer + Signed + Copy>(a: T, b: T, size: T) -> T {
+    let d = (a - b).mod_floor(&size);
+    d.min(size - d)
+}
+
+impl<T: Integer + Signed + Copy> Torus<T> {
+    /// # Panics
+    ///
+    /// Panics if the width or height isn't positive.
+    pub fn new(width: T, height: T) -> Self {
+        assert!(
+            width.is_positive() && height.is_positive(),
+            "a torus must have a positive size"
+        );
+
+        Self {
+            size: Vector2D::new(width, height),
+        }
+    }
+
+    pub fn width(&self) -> T {
+        self.size.x
+    }
+
+    pub fn height(&self) -> T {
+        self.size.y
+    }
+
+    pub fn wrap(&self, pos: Vector2D<T>) -> Vector2D<T> {
+        pos.rem_euclid(self.size)
+    }
+
+    /// Wraps an index, whose `i` is the row and `j` the column.
+    pub fn wrap_index(&self, pos: Index2D<T>) -> Index2D<T> {
+        Index2D::new(pos.i.mod_floor(&self.size.y), pos.j.mod_floor(&self.size.x))
+    }
+
+    pub fn add(&self, pos: Vector2D<T>, offset: Vector2D<T>) -> Vector2D<T> {
+        self.wrap(pos + offset)
+    }
+
+    pub fn sub(&self, pos: Vector2D<T>, offset: Vector2D<T>) -> Vector2D<T> {
+        self.wrap(pos - offset)
+    }
+
+    /// Moves `steps` times by `velocity`, i.e. `pos + velocity * steps` wrapped. Everything is
+    /// reduced first, so that nothing overflows as long as the size squared fits in `T`.
+    pub fn advance(&self, pos: Vector2D<T>, velocity: Vector2D<T>, steps: T) -> Vector2D<T> {
+        let along = |pos: T, velocity: T, size: T| {
+            let moved = velocity.mod_floor(&size) * steps.mod_floor(&size);
+            (pos.mod_floor(&size) + moved.mod_floor(&size)).mod_floor(&size)
+        };
+
+        Vector2D::new(
+            along(pos.x, velocity.x, self.size.x),
+            along(pos.y, velocity.y, self.size.y),
+        )
+    }
+
+    /// The number of orthogonal steps between two positions, going around the edges when that
+    /// is shorter.
+    pub fn manhattan_distance(&self, a: Vector2D<T>, b: Vector2D<T>) -> T {
+        wrapping_distance(a.x, b.x, self.size.x) + wrapping_distance(a.y, b.y, self.size.y)
+    }
+
+    /// Returns the 4 orthogonal neighbors, up, right, down then left, wrapped. On a torus less
+    /// than 3 wide or high some of them are the same position.
+    pub fn neighbors(&self, pos: Vector2D<T>) -> impl Iterator<Item = Vector2D<T>> + '_ {
+        DIRECTIONS
+            .into_iter()
+            .map(move |direction| self.add(pos, direction.vector()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fastrand::Rng;
+
+    use super::*;
+
+    #[test]
+    fn positions_wrap_around() {
+        let torus = Torus::new(11, 7);
+
+        assert_eq!(torus.wrap(Vector2D::new(-1, 15)), Vector2D::new(10, 1));
+        assert_eq!(torus.wrap_index(Index2D::new(-1, 15)), Index2D::new(6, 4));
+        assert_eq!(
+            torus.manhattan_distance(Vector2D::new(0, 0), Vector2D::new(10, 4)),
+            4
+        );
+        assert_eq!(
+            torus.neighbors(Vector2D::new(0, 0)).collect::<Vec<_>>(),
+            [
+                Vector2D::new(0, 6),
+                Vector2D::new(1, 0),
+                Vector2D::new(0, 1),
+                Vector2D::new(10, 0),
+            ]
+        );
+
+        let mut rng = Rng::with_seed(0);
+        for _ in 0..1000 {
+            let pos = Vector2D::new(rng.i64(-100..100), rng.i64(-100..100));
+            let velocity = Vector2D::new(rng.i64(-100..100), rng.i64(-100..100));
+            let steps = rng.i64(0..1000);
+            let torus = Torus::new(rng.i64(1..50), rng.i64(1..50));
+
+            assert_eq!(
+                torus.advance(pos, velocity, steps),
+                torus.wrap(pos + velocity * steps)
+            );
+        }
+
+        let huge = Torus::new(101, 103);
+        assert_eq!(
+            huge.advance(Vector2D::new(2, 4), Vector2D::new(2, -3), i32::MAX),
+            huge.wrap(Vector2D::new(
+                2 + 2 * (i32::MAX % 101),
+                4 - 3 * (i32::MAX % 103)
+            ))
+        );
+    }
+}
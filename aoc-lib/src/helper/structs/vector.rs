@@ -1,6 +1,8 @@
 use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
-use num::Float;
+use num::{Float, Integer, Signed};
+
+use crate::helper::math::gcd;
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
 pub struct Vector2D<T> {
@@ -22,11 +24,72 @@ impl<T> Vector2D<T> {
     }
 }
 
-impl<T: Float> Vector2D<T> {
+impl<T: Copy + Add<Output = T> + Mul<Output = T>> Vector2D<T> {
     pub fn dot(self, other: Self) -> T {
-        self.x * other.x + self.y + other.y
+        self.x * other.x + self.y * other.y
+    }
+}
+
+impl<T: Copy + Sub<Output = T> + Mul<Output = T>> Vector2D<T> {
+    /// The z component of the cross product, which is positive when `other` is turned
+    /// counter-clockwise from `self` with `y` growing upwards, and zero when they are parallel.
+    pub fn cross(self, other: Self) -> T {
+        self.x * other.y - self.y * other.x
+    }
+}
+
+impl<T: Integer + Signed + Copy> Vector2D<T> {
+    pub fn manhattan_length(self) -> T {
+        self.x.abs() + self.y.abs()
     }
 
+    /// Wraps both components into `0..size`, as when moving on a map whose edges wrap around.
+    pub fn rem_euclid(self, size: Self) -> Self {
+        Self {
+            x: self.x.mod_floor(&size.x),
+            y: self.y.mod_floor(&size.y),
+        }
+    }
+
+    /// Divides both components by their greatest common divisor, giving the smallest step in the
+    /// same direction that lands on integer coordinates. The zero vector is returned unchanged.
+    pub fn primitive(self) -> Self {
+        let divisor = gcd(self.x.abs(), self.y.abs());
+
+        if divisor.is_zero() {
+            self
+        } else {
+            self / divisor
+        }
+    }
+}
+
+/// Twice the area of a simple polygon, from its vertices in order, using the shoelace formula.
+/// Doubling keeps the result exact for integer vertices.
+pub fn polygon_double_area<T: Integer + Signed + Copy>(vertices: &[Vector2D<T>]) -> T {
+    let edges = vertices.iter().zip(vertices.iter().cycle().skip(1));
+    edges
+        .fold(T::zero(), |sum, (&a, &b)| sum + a.cross(b))
+        .abs()
+}
+
+/// The number of integer points on the edges of a polygon, from its vertices in order.
+pub fn polygon_boundary_points<T: Integer + Signed + Copy>(vertices: &[Vector2D<T>]) -> T {
+    let edges = vertices.iter().zip(vertices.iter().cycle().skip(1));
+    edges.fold(T::zero(), |sum, (&a, &b)| {
+        let edge = b - a;
+        sum + gcd(edge.x.abs(), edge.y.abs())
+    })
+}
+
+/// The number of integer points strictly inside a simple polygon with integer vertices, using
+/// Pick's theorem.
+pub fn polygon_interior_points<T: Integer + Signed + Copy>(vertices: &[Vector2D<T>]) -> T {
+    let two = T::one() + T::one();
+    (polygon_double_area(vertices) - polygon_boundary_points(vertices) + two) / two
+}
+
+impl<T: Float> Vector2D<T> {
     pub fn norm_sq(self) -> T {
         self.x.powi(2) + self.y.powi(2)
     }
@@ -140,3 +203,119 @@ impl<T: DivAssign + Copy> DivAssign<T> for Vector2D<T> {
         self.y /= rhs;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use fastrand::Rng;
+
+    use super::*;
+
+    const CASES: u64 = 1000;
+
+    fn random_vector(rng: &mut Rng) -> Vector2D<i64> {
+        Vector2D::new(rng.i64(-1000..=1000), rng.i64(-1000..=1000))
+    }
+
+    #[test]
+    fn products_are_exact() {
+        let mut rng = Rng::with_seed(0);
+
+        for _ in 0..CASES {
+            let a = random_vector(&mut rng);
+            let b = random_vector(&mut rng);
+            let perpendicular = Vector2D::new(b.y, -b.x);
+
+            assert_eq!(a.dot(b), b.dot(a));
+            assert_eq!(a.dot(a), a.x * a.x + a.y * a.y);
+            assert_eq!(a.cross(b), -b.cross(a));
+            assert_eq!(a.cross(a * 3), 0);
+            assert_eq!(a.cross(b), a.dot(perpendicular));
+            assert!(a.manhattan_length() + b.manhattan_length() >= (a + b).manhattan_length());
+        }
+
+        assert_eq!(Vector2D::new(1.0, 2.0).dot(Vector2D::new(3.0, 4.0)), 11.0);
+    }
+
+    #[test]
+    fn wrapping_stays_in_bounds() {
+        let mut rng = Rng::with_seed(1);
+
+        for _ in 0..CASES {
+            let v = random_vector(&mut rng);
+            let size = Vector2D::new(rng.i64(1..100), rng.i64(1..100));
+            let wrapped = v.rem_euclid(size);
+
+            assert!((0..size.x).contains(&wrapped.x) && (0..size.y).contains(&wrapped.y));
+            assert_eq!((v.x - wrapped.x) % size.x, 0);
+            assert_eq!((v.y - wrapped.y) % size.y, 0);
+            assert_eq!(wrapped.rem_euclid(size), wrapped);
+        }
+    }
+
+    #[test]
+    fn primitive_directions_are_reduced() {
+        let mut rng = Rng::with_seed(2);
+
+        for _ in 0..CASES {
+            let v = random_vector(&mut rng);
+            let primitive = v.primitive();
+            let scale = gcd(v.x.abs(), v.y.abs());
+
+            assert_eq!(primitive * scale, v);
+            if v != Vector2D::default() {
+                assert_eq!(gcd(primitive.x.abs(), primitive.y.abs()), 1);
+                assert_eq!(primitive.cross(v), 0);
+                assert!(primitive.dot(v) > 0);
+            }
+        }
+    }
+
+    #[test]
+    fn polygon_areas_follow_picks_theorem() {
+        let mut rng = Rng::with_seed(3);
+
+        for _ in 0..CASES {
+            let corner = random_vector(&mut rng);
+            let (w, h) = (rng.i64(1..50), rng.i64(1..50));
+            let rectangle =
+                [(0, 0), (w, 0), (w, h), (0, h)].map(|(x, y)| corner + Vector2D::new(x, y));
+
+            assert_eq!(polygon_double_area(&rectangle), 2 * w * h);
+            assert_eq!(polygon_boundary_points(&rectangle), 2 * (w + h));
+            assert_eq!(polygon_interior_points(&rectangle), (w - 1) * (h - 1));
+
+            // Small enough to count the points one by one, inside the bounding box.
+            let mut triangle = [(); 3].map(|_| Vector2D::new(rng.i64(0..12), rng.i64(0..12)));
+            let area = polygon_double_area(&triangle);
+            let boundary = polygon_boundary_points(&triangle);
+
+            if area > 0 {
+                let (mut inside, mut on_edge) = (0, 0);
+
+                for x in 0..12 {
+                    for y in 0..12 {
+                        let p = Vector2D::new(x, y);
+                        let sides = [0, 1, 2].map(|k| {
+                            let (a, b) = (triangle[k], triangle[(k + 1) % 3]);
+                            (b - a).cross(p - a).signum()
+                        });
+
+                        if sides.iter().all(|&s| s >= 0) || sides.iter().all(|&s| s <= 0) {
+                            match sides.contains(&0) {
+                                true => on_edge += 1,
+                                false => inside += 1,
+                            }
+                        }
+                    }
+                }
+
+                assert_eq!(boundary, on_edge);
+                assert_eq!(polygon_interior_points(&triangle), inside);
+            }
+
+            triangle.reverse();
+            assert_eq!(polygon_double_area(&triangle), area);
+            assert_eq!(polygon_boundary_points(&triangle), boundary);
+        }
+    }
+}
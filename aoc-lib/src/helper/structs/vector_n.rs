@@ -0,0 +1,302 @@
+use std::{
+    array,
+    iter::Sum,
+    ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use num::Signed;
+
+use super::{Index2D, Vector2D};
+
+/// A vector with any number of components, for puzzles in 3 or more dimensions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VectorN<T, const N: usize>(pub [T; N]);
+
+pub type Vector3D<T> = VectorN<T, 3>;
+pub type Vector4D<T> = VectorN<T, 4>;
+
+impl<T, const N: usize> VectorN<T, N> {
+    pub fn new(components: [T; N]) -> Self {
+        Self(components)
+    }
+
+    pub fn to_array(self) -> [T; N] {
+        self.0
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.0.iter()
+    }
+}
+
+impl<T: Default, const N: usize> Default for VectorN<T, N> {
+    fn default() -> Self {
+        Self(array::from_fn(|_| T::default()))
+    }
+}
+
+impl<T: Copy, const N: usize> VectorN<T, N> {
+    /// Combines the components of two vectors pairwise.
+    fn zip_with<F: FnMut(T, T) -> T>(self, other: Self, mut f: F) -> Self {
+        Self(array::from_fn(|k| f(self.0[k], other.0[k])))
+    }
+
+    fn map<F: FnMut(T) -> T>(self, f: F) -> Self {
+        Self(self.0.map(f))
+    }
+}
+
+impl<T: Copy + Ord, const N: usize> VectorN<T, N> {
+    /// The component-wise minimum, such as the lower corner of a bounding box.
+    pub fn min(self, other: Self) -> Self {
+        self.zip_with(other, T::min)
+    }
+
+    /// The component-wise maximum, such as the upper corner of a bounding box.
+    pub fn max(self, other: Self) -> Self {
+        self.zip_with(other, T::max)
+    }
+}
+
+/// Turns a base-3 digit into an offset of -1, 0 or 1.
+fn unit_offset<T: Signed>(digit: usize) -> T {
+    match digit {
+        0 => -T::one(),
+        1 => T::zero(),
+        _ => T::one(),
+    }
+}
+
+impl<T: Copy + Signed, const N: usize> VectorN<T, N> {
+    /// The sum of the absolute values of the components.
+    pub fn manhattan_norm(self) -> T {
+        self.0.iter().fold(T::zero(), |sum, &c| sum + c.abs())
+    }
+
+    pub fn manhattan_distance(self, other: Self) -> T {
+        (self - other).manhattan_norm()
+    }
+
+    /// Returns the 3^N - 1 neighbors that differ by at most one in every component.
+    pub fn neighbors(self) -> impl Iterator<Item = Self> {
+        let count = 3usize.pow(N as u32);
+
+        (0..count)
+            .filter(move |&k| k != count / 2)
+            .map(move |mut k| {
+                let mut neighbor = self;
+
+                for c in neighbor.0.iter_mut() {
+                    *c = *c + unit_offset(k % 3);
+                    k /= 3;
+                }
+
+                neighbor
+            })
+    }
+
+    /// Returns the 2N neighbors that differ by one in a single component.
+    pub fn orthogonal_neighbors(self) -> impl Iterator<Item = Self> {
+        (0..N).flat_map(move |k| {
+            [-T::one(), T::one()].into_iter().map(move |delta| {
+                let mut neighbor = self;
+                neighbor.0[k] = neighbor.0[k] + delta;
+                neighbor
+            })
+        })
+    }
+}
+
+impl<T: Copy + Default + Add<Output = T> + Mul<Output = T>, const N: usize> VectorN<T, N> {
+    pub fn dot(self, other: Self) -> T {
+        self.0
+            .iter()
+            .zip(other.0)
+            .fold(T::default(), |sum, (&a, b)| sum + a * b)
+    }
+}
+
+impl<T: Copy + Mul<Output = T> + Sub<Output = T>> VectorN<T, 3> {
+    pub fn cross(self, other: Self) -> Self {
+        let [ax, ay, az] = self.0;
+        let [bx, by, bz] = other.0;
+
+        Self([ay * bz - az * by, az * bx - ax * bz, ax * by - ay * bx])
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for VectorN<T, N> {
+    fn from(components: [T; N]) -> Self {
+        Self(components)
+    }
+}
+
+impl<T> From<Vector2D<T>> for VectorN<T, 2> {
+    fn from(vector: Vector2D<T>) -> Self {
+        Self(vector.to_array())
+    }
+}
+
+impl<T> From<Index2D<T>> for VectorN<T, 2> {
+    fn from(index: Index2D<T>) -> Self {
+        Self([index.i, index.j])
+    }
+}
+
+impl<T> From<VectorN<T, 2>> for Vector2D<T> {
+    fn from(vector: VectorN<T, 2>) -> Self {
+        let [x, y] = vector.0;
+        Self::new(x, y)
+    }
+}
+
+impl<T> From<VectorN<T, 2>> for Index2D<T> {
+    fn from(vector: VectorN<T, 2>) -> Self {
+        let [i, j] = vector.0;
+        Self::new(i, j)
+    }
+}
+
+impl<T, const N: usize> Index<usize> for VectorN<T, N> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for VectorN<T, N> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.0[index]
+    }
+}
+
+impl<T: Neg<Output = T>, const N: usize> Neg for VectorN<T, N> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(self.0.map(T::neg))
+    }
+}
+
+impl<T: Copy + Add<Output = T>, const N: usize> Add for VectorN<T, N> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.zip_with(rhs, T::add)
+    }
+}
+
+impl<T: Copy + AddAssign, const N: usize> AddAssign for VectorN<T, N> {
+    fn add_assign(&mut self, rhs: Self) {
+        for (c, rhs) in self.0.iter_mut().zip(rhs.0) {
+            *c += rhs;
+        }
+    }
+}
+
+impl<T: Copy + Sub<Output = T>, const N: usize> Sub for VectorN<T, N> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.zip_with(rhs, T::sub)
+    }
+}
+
+impl<T: Copy + SubAssign, const N: usize> SubAssign for VectorN<T, N> {
+    fn sub_assign(&mut self, rhs: Self) {
+        for (c, rhs) in self.0.iter_mut().zip(rhs.0) {
+            *c -= rhs;
+        }
+    }
+}
+
+impl<T: Copy + Mul<Output = T>, const N: usize> Mul<T> for VectorN<T, N> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        self.map(|c| c * rhs)
+    }
+}
+
+impl<T: Copy + MulAssign, const N: usize> MulAssign<T> for VectorN<T, N> {
+    fn mul_assign(&mut self, rhs: T) {
+        for c in self.0.iter_mut() {
+            *c *= rhs;
+        }
+    }
+}
+
+impl<T: Copy + Div<Output = T>, const N: usize> Div<T> for VectorN<T, N> {
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self::Output {
+        self.map(|c| c / rhs)
+    }
+}
+
+impl<T: Copy + DivAssign, const N: usize> DivAssign<T> for VectorN<T, N> {
+    fn div_assign(&mut self, rhs: T) {
+        for c in self.0.iter_mut() {
+            *c /= rhs;
+        }
+    }
+}
+
+impl<T: Copy + Default + Add<Output = T>, const N: usize> Sum for VectorN<T, N> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), Add::add)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn vectors_work() {
+        let a = Vector3D::new([1, -2, 3]);
+        let b = Vector3D::new([-4, 5, 6]);
+
+        assert_eq!(a + b, VectorN([-3, 3, 9]));
+        assert_eq!(-a * 2, VectorN([-2, 4, -6]));
+        assert_eq!(a.min(b), VectorN([-4, -2, 3]));
+        assert_eq!(a.max(b), VectorN([1, 5, 6]));
+        assert_eq!(a.manhattan_distance(b), 15);
+        assert_eq!([a, b].into_iter().sum::<Vector3D<i32>>(), a + b);
+
+        let x = Vector3D::new([1, 0, 0]);
+        let y = Vector3D::new([0, 1, 0]);
+        assert_eq!(x.cross(y), VectorN([0, 0, 1]));
+        assert_eq!(y.cross(x), VectorN([0, 0, -1]));
+        assert_eq!(a.dot(b), 4);
+        assert_eq!(x.dot(y), 0);
+        assert_eq!(x.cross(y).dot(x), 0);
+
+        assert_eq!(VectorN::from(Index2D::new(1, 2)), VectorN([1, 2]));
+        assert_eq!(
+            Index2D::from(VectorN::from(Index2D::new(5, 6))),
+            Index2D::new(5, 6)
+        );
+        assert_eq!(
+            Vector2D::from(VectorN::from(Vector2D::new(3, 4))),
+            Vector2D::new(3, 4)
+        );
+    }
+
+    #[test]
+    fn neighbors_are_enumerated() {
+        let origin = Vector4D::<i64>::default();
+
+        let neighbors = origin.neighbors().collect::<HashSet<_>>();
+        assert_eq!(neighbors.len(), 80);
+        assert!(!neighbors.contains(&origin));
+        assert!(neighbors.iter().all(|n| n.iter().all(|c| c.abs() <= 1)));
+
+        let orthogonal = origin.orthogonal_neighbors().collect::<HashSet<_>>();
+        assert_eq!(orthogonal.len(), 8);
+        assert!(orthogonal.iter().all(|n| n.manhattan_norm() == 1));
+    }
+}
@@ -1,2 +1,5 @@
 pub mod cli;
 pub mod helper;
+pub mod visual;
+
+pub use cli::span;
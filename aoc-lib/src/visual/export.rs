@@ -0,0 +1,462 @@
+use super::{player, Animation, Cell, Color, Frame};
+use std::{
+    collections::HashMap,
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+pub type Rgb = [u8; 3];
+
+/// The colors used to paint cells when exporting to an image format. Each cell is painted as a
+/// solid square: its background color if it has one, the palette background if its character
+/// is whitespace, and its foreground color otherwise.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette {
+    pub background: Rgb,
+    pub foreground: Rgb,
+    colors: HashMap<Color, Rgb>,
+}
+
+impl Palette {
+    pub fn dark() -> Self {
+        Self {
+            background: [16, 16, 24],
+            foreground: [220, 220, 220],
+            colors: HashMap::from([
+                (Color::Black, [0, 0, 0]),
+                (Color::Red, [230, 70, 70]),
+                (Color::Green, [90, 200, 90]),
+                (Color::Yellow, [240, 200, 60]),
+                (Color::Blue, [80, 120, 230]),
+                (Color::Magenta, [200, 90, 200]),
+                (Color::Cyan, [70, 200, 210]),
+                (Color::White, [255, 255, 255]),
+                (Color::Grey, [60, 60, 70]),
+            ]),
+        }
+    }
+
+    pub fn light() -> Self {
+        Self {
+            background: [250, 250, 245],
+            foreground: [30, 30, 30],
+            colors: HashMap::from([
+                (Color::Black, [0, 0, 0]),
+                (Color::Red, [200, 40, 40]),
+                (Color::Green, [40, 150, 40]),
+                (Color::Yellow, [200, 150, 0]),
+                (Color::Blue, [40, 80, 200]),
+                (Color::Magenta, [160, 50, 160]),
+                (Color::Cyan, [20, 150, 160]),
+                (Color::White, [255, 255, 255]),
+                (Color::Grey, [215, 215, 210]),
+            ]),
+        }
+    }
+
+    pub fn by_name(name: &str) -> Option<Self> {
+        match name {
+            "dark" => Some(Self::dark()),
+            "light" => Some(Self::light()),
+            _ => None,
+        }
+    }
+
+    pub fn with_color(mut self, color: Color, rgb: Rgb) -> Self {
+        self.colors.insert(color, rgb);
+        self
+    }
+
+    pub fn color(&self, color: Color) -> Rgb {
+        match color {
+            Color::Rgb(r, g, b) => [r, g, b],
+            _ => self.colors.get(&color).copied().unwrap_or(self.foreground),
+        }
+    }
+
+    pub fn cell_color(&self, cell: &Cell) -> Rgb {
+        if let Some(bg) = cell.bg {
+            self.color(bg)
+        } else if cell.ch.is_whitespace() {
+            self.background
+        } else {
+            cell.fg.map_or(self.foreground, |fg| self.color(fg))
+        }
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportOptions {
+    /// Side length in pixels of the square painted for each cell.
+    pub cell_size: u32,
+    pub palette: Palette,
+    pub fps: u32,
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        Self {
+            cell_size: 4,
+            palette: Palette::default(),
+            fps: 10,
+        }
+    }
+}
+
+fn frame_size(animation: &Animation) -> (usize, usize) {
+    animation
+        .frames()
+        .iter()
+        .fold((0, 0), |(height, width), frame| {
+            (height.max(frame.height()), width.max(frame.width()))
+        })
+}
+
+/// Paints a frame as RGB pixels, padding it with the background color up to the given size.
+fn rasterize(frame: &Frame, height: usize, width: usize, options: &ExportOptions) -> Vec<Rgb> {
+    let cell_size = options.cell_size as usize;
+    let pixel_width = width * cell_size;
+    let mut pixels = vec![options.palette.background; height * cell_size * pixel_width];
+
+    for (i, row) in frame.rows().enumerate() {
+        for (j, cell) in row.iter().enumerate() {
+            let color = options.palette.cell_color(cell);
+
+            for y in (i * cell_size)..((i + 1) * cell_size) {
+                let start = y * pixel_width + j * cell_size;
+                pixels[start..(start + cell_size)].fill(color);
+            }
+        }
+    }
+
+    pixels
+}
+
+pub fn export_gif<W: Write>(
+    animation: &Animation,
+    options: &ExportOptions,
+    writer: W,
+) -> io::Result<()> {
+    let (height, width) = frame_size(animation);
+    let cell_size = options.cell_size as usize;
+    let (pixel_height, pixel_width) = (height * cell_size, width * cell_size);
+
+    let (Ok(gif_width), Ok(gif_height)) = (u16::try_from(pixel_width), u16::try_from(pixel_height))
+    else {
+        return Err(io::Error::other("frames are too large for a GIF"));
+    };
+
+    let mut encoder =
+        gif::Encoder::new(writer, gif_width, gif_height, &[]).map_err(io::Error::other)?;
+    encoder
+        .set_repeat(gif::Repeat::Infinite)
+        .map_err(io::Error::other)?;
+
+    let delay = (100 / options.fps.max(1)).max(1) as u16;
+    let mut palette = Vec::<Rgb>::new();
+
+    for frame in animation.frames() {
+        let pixels = rasterize(frame, height, width, options);
+
+        // Frames are usually drawn with a handful of colors, so an exact palette is built where
+        // possible and quantization is only a fallback.
+        let indices = pixels
+            .iter()
+            .map(|color| match palette.iter().position(|c| c == color) {
+                Some(index) => Some(index as u8),
+                None if palette.len() < 256 => {
+                    palette.push(*color);
+                    Some((palette.len() - 1) as u8)
+                }
+                None => None,
+            })
+            .collect::<Option<Vec<_>>>();
+
+        let mut gif_frame = match indices {
+            Some(indices) => {
+                let flat_palette = palette.concat();
+                gif::Frame::from_palette_pixels(gif_width, gif_height, indices, flat_palette, None)
+            }
+            None => gif::Frame::from_rgb(gif_width, gif_height, &pixels.concat()),
+        };
+
+        gif_frame.delay = delay;
+        encoder.write_frame(&gif_frame).map_err(io::Error::other)?;
+    }
+
+    Ok(())
+}
+
+pub fn export_png<W: Write>(frame: &Frame, options: &ExportOptions, writer: W) -> io::Result<()> {
+    let pixels = rasterize(frame, frame.height(), frame.width(), options);
+    let cell_size = options.cell_size;
+
+    let mut encoder = png::Encoder::new(
+        writer,
+        frame.width() as u32 * cell_size,
+        frame.height() as u32 * cell_size,
+    );
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+
+    let mut writer = encoder.write_header().map_err(io::Error::other)?;
+    writer
+        .write_image_data(&pixels.concat())
+        .map_err(io::Error::other)
+}
+
+/// Writes the animation in the asciinema v2 format, with colors kept as ANSI escape codes.
+pub fn export_cast<W: Write>(
+    animation: &Animation,
+    options: &ExportOptions,
+    mut writer: W,
+) -> io::Result<()> {
+    let (height, width) = frame_size(animation);
+    let header = serde_json::json!({
+        "version": 2,
+        "width": width,
+        "height": height + 1,
+    });
+    writeln!(writer, "{}", header)?;
+
+    for (k, frame) in animation.frames().iter().enumerate() {
+        let mut data = b"\x1b[H\x1b[2J".to_vec();
+
+        for (i, row) in frame.rows().enumerate() {
+            if i > 0 {
+                data.extend_from_slice(b"\r\n");
+            }
+            player::draw_cells(&mut data, row.iter())?;
+        }
+
+        if let Some(caption) = frame.caption() {
+            data.extend_from_slice(b"\r\n");
+            data.extend_from_slice(caption.as_bytes());
+        }
+
+        let time = k as f64 / options.fps.max(1) as f64;
+        let event = serde_json::json!([time, "o", String::from_utf8_lossy(&data)]);
+        writeln!(writer, "{}", event)?;
+    }
+
+    Ok(())
+}
+
+/// Returns the path of the `index`-th PNG exported for `path`, e.g. `robots_0042.png` for
+/// `robots.png`.
+fn numbered_path(path: &Path, index: usize, count: usize) -> PathBuf {
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let digits = count.to_string().len();
+    path.with_file_name(format!("{}_{:0digits$}.png", stem, index, digits = digits))
+}
+
+/// Exports the animation to `path`, choosing the format from its extension: `.gif` for an
+/// animated GIF, `.cast` for an asciicast, and `.png` for one numbered PNG per frame.
+pub fn export(animation: &Animation, options: &ExportOptions, path: &Path) -> io::Result<()> {
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)?;
+    }
+
+    let extension = path.extension().and_then(|ext| ext.to_str());
+
+    match extension {
+        Some("gif") => export_gif(animation, options, BufWriter::new(File::create(path)?)),
+        Some("cast") => export_cast(animation, options, BufWriter::new(File::create(path)?)),
+        Some("png") => {
+            for (k, frame) in animation.frames().iter().enumerate() {
+                let file = File::create(numbered_path(path, k + 1, animation.len()))?;
+                export_png(frame, options, BufWriter::new(file))?;
+            }
+            Ok(())
+        }
+        _ => Err(io::Error::other(format!(
+            "unsupported export format: {} (expected .gif, .png or .cast)",
+            path.display()
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_animation() -> Animation {
+        let mut animation = Animation::new();
+        animation.push(Frame::from_text("#.\n.#"));
+        animation.push(Frame::from_text("##\n #").with_caption("done"));
+        animation
+    }
+
+    #[test]
+    fn rasterize_works() {
+        let options = ExportOptions {
+            cell_size: 2,
+            palette: Palette::dark().with_color(Color::Red, [255, 0, 0]),
+            fps: 10,
+        };
+        let mut frame = Frame::from_text("# ");
+        frame.set(0, 1, Cell::new('x').fg(Color::Red));
+
+        let pixels = rasterize(&frame, 2, 2, &options);
+        let (fg, bg, red) = ([220, 220, 220], [16, 16, 24], [255, 0, 0]);
+
+        assert_eq!(pixels.len(), 16);
+        assert_eq!(&pixels[..4], &[fg, fg, red, red]);
+        assert_eq!(&pixels[4..8], &[fg, fg, red, red]);
+        assert!(pixels[8..].iter().all(|&pixel| pixel == bg));
+    }
+
+    #[test]
+    fn cast_export_works() {
+        let mut output = Vec::new();
+        export_cast(&sample_animation(), &ExportOptions::default(), &mut output).unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+        let lines = output.lines().collect::<Vec<_>>();
+
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], r#"{"height":3,"version":2,"width":2}"#);
+        assert!(lines[2].starts_with("[0.1,\"o\","));
+        assert!(lines[2].contains("done"));
+    }
+
+    /// The delay and RGB pixels of a decoded GIF frame.
+    type DecodedFrame = (u16, Vec<Rgb>);
+
+    /// Decodes a GIF into its size and frames.
+    fn decode_gif(data: &[u8]) -> ((u16, u16), Vec<DecodedFrame>) {
+        let mut options = gif::DecodeOptions::new();
+        options.set_color_output(gif::ColorOutput::RGBA);
+        let mut decoder = options.read_info(data).unwrap();
+
+        let mut frames = Vec::new();
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            let pixels = frame
+                .buffer
+                .chunks(4)
+                .map(|rgba| [rgba[0], rgba[1], rgba[2]])
+                .collect();
+            frames.push((frame.delay, pixels));
+        }
+
+        ((decoder.width(), decoder.height()), frames)
+    }
+
+    #[test]
+    fn gif_export_works() {
+        let options = ExportOptions {
+            cell_size: 2,
+            palette: Palette::dark(),
+            fps: 20,
+        };
+        let mut output = Vec::new();
+        export_gif(&sample_animation(), &options, &mut output).unwrap();
+
+        let (size, frames) = decode_gif(&output);
+        let (fg, bg) = ([220, 220, 220], [16, 16, 24]);
+
+        assert_eq!(size, (4, 4));
+        assert_eq!(frames.len(), 2);
+        assert!(frames.iter().all(|(delay, _)| *delay == 5));
+        assert!(frames[0].1.iter().all(|&pixel| pixel == fg));
+
+        // The background only appears in the second frame, so it's added to the palette then.
+        let second = &frames[1].1;
+        assert_eq!(&second[..4], &[fg; 4]);
+        assert_eq!(&second[8..12], &[bg, bg, fg, fg]);
+    }
+
+    #[test]
+    fn gif_export_quantizes_frames_with_many_colors() {
+        let color = |j: usize| Color::Rgb((j % 256) as u8, if j < 256 { 0 } else { 255 }, 0);
+        let options = ExportOptions {
+            cell_size: 1,
+            ..ExportOptions::default()
+        };
+
+        let mut many = Frame::from_text(&" ".repeat(300));
+        let mut few = many.clone();
+        for j in 1..300 {
+            many.set(0, j, Cell::new(' ').bg(color(j)));
+        }
+        for j in [1, 100, 255] {
+            few.set(0, j, Cell::new(' ').bg(color(j)));
+        }
+
+        let mut animation = Animation::new();
+        animation.push(many);
+        animation.push(few);
+
+        let mut output = Vec::new();
+        export_gif(&animation, &options, &mut output).unwrap();
+        let (size, frames) = decode_gif(&output);
+
+        assert_eq!(size, (300, 1));
+        assert_eq!(frames.len(), 2);
+
+        // The first frame has more than 256 colors, so it falls back to a quantized palette.
+        for (j, pixel) in frames[0].1.iter().enumerate().skip(1) {
+            let expected = options.palette.color(color(j));
+            let error: u32 = pixel
+                .iter()
+                .zip(expected)
+                .map(|(&x, y)| x.abs_diff(y) as u32)
+                .sum();
+            assert!(
+                error <= 32,
+                "cell {j} was painted {pixel:?} instead of {expected:?}"
+            );
+        }
+
+        // The second frame only uses colors that made it into the exact palette.
+        let second = &frames[1].1;
+        assert_eq!(second[0], options.palette.background);
+        assert_eq!(second[100], [100, 0, 0]);
+        assert_eq!(second[255], [255, 0, 0]);
+        assert_eq!(second[256], options.palette.background);
+    }
+
+    #[test]
+    fn png_export_works() {
+        let options = ExportOptions {
+            cell_size: 3,
+            palette: Palette::dark().with_color(Color::Green, [0, 255, 0]),
+            fps: 10,
+        };
+        let mut frame = Frame::from_text("#  \n ");
+        frame.set(0, 2, Cell::new('o').fg(Color::Green));
+
+        let mut output = Vec::new();
+        export_png(&frame, &options, &mut output).unwrap();
+
+        let mut reader = png::Decoder::new(output.as_slice()).read_info().unwrap();
+        let mut pixels = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut pixels).unwrap();
+        let pixel = |x: usize, y: usize| {
+            let start = 3 * (y * info.width as usize + x);
+            &pixels[start..(start + 3)]
+        };
+
+        assert_eq!((info.width, info.height), (9, 6));
+        assert_eq!(info.color_type, png::ColorType::Rgb);
+        assert_eq!(pixel(2, 2), [220, 220, 220]);
+        assert_eq!(pixel(4, 1), [16, 16, 24]);
+        assert_eq!(pixel(8, 0), [0, 255, 0]);
+        assert_eq!(pixel(8, 5), [16, 16, 24]);
+    }
+
+    #[test]
+    fn numbered_path_works() {
+        assert_eq!(
+            numbered_path(Path::new("out/robots.png"), 42, 10403),
+            PathBuf::from("out/robots_00042.png")
+        );
+    }
+}
@@ -0,0 +1,209 @@
+use std::fmt::Display;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Grey,
+    Rgb(u8, u8, u8),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cell {
+    pub ch: char,
+    pub fg: Option<Color>,
+    pub bg: Option<Color>,
+    pub bold: bool,
+}
+
+impl Cell {
+    pub const fn new(ch: char) -> Self {
+        Self {
+            ch,
+            fg: None,
+            bg: None,
+            bold: false,
+        }
+    }
+
+    pub const fn fg(mut self, color: Color) -> Self {
+        self.fg = Some(color);
+        self
+    }
+
+    pub const fn bg(mut self, color: Color) -> Self {
+        self.bg = Some(color);
+        self
+    }
+
+    pub const fn bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Self::new(' ')
+    }
+}
+
+impl From<char> for Cell {
+    fn from(ch: char) -> Self {
+        Self::new(ch)
+    }
+}
+
+/// A single picture of a visualization: a grid of styled cells, plus an optional caption.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    height: usize,
+    width: usize,
+    cells: Vec<Cell>,
+    caption: Option<String>,
+}
+
+impl Frame {
+    pub fn new(height: usize, width: usize) -> Self {
+        Self::filled(height, width, Cell::default())
+    }
+
+    pub fn filled(height: usize, width: usize, cell: Cell) -> Self {
+        Self {
+            height,
+            width,
+            cells: vec![cell; height * width],
+            caption: None,
+        }
+    }
+
+    /// Creates an unstyled frame from lines of text, padding short lines with spaces.
+    pub fn from_text(text: &str) -> Self {
+        let lines = text.lines().collect::<Vec<_>>();
+        let width = lines
+            .iter()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+
+        let mut frame = Self::new(lines.len(), width);
+
+        for (i, line) in lines.iter().enumerate() {
+            for (j, ch) in line.chars().enumerate() {
+                frame.set(i, j, ch);
+            }
+        }
+
+        frame
+    }
+
+    pub fn with_caption<S: Into<String>>(mut self, caption: S) -> Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    pub fn set_caption<S: Into<String>>(&mut self, caption: S) {
+        self.caption = Some(caption.into());
+    }
+
+    pub fn caption(&self) -> Option<&str> {
+        self.caption.as_deref()
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn get(&self, i: usize, j: usize) -> Option<&Cell> {
+        if i < self.height && j < self.width {
+            self.cells.get(i * self.width + j)
+        } else {
+            None
+        }
+    }
+
+    /// Sets the cell at `(i, j)`. Positions outside the frame are ignored.
+    pub fn set<C: Into<Cell>>(&mut self, i: usize, j: usize, cell: C) {
+        if i < self.height && j < self.width {
+            self.cells[i * self.width + j] = cell.into();
+        }
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[Cell]> {
+        self.cells.chunks(self.width.max(1)).take(self.height)
+    }
+}
+
+impl Display for Frame {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            let line = row.iter().map(|cell| cell.ch).collect::<String>();
+            writeln!(f, "{}", line.trim_end())?;
+        }
+
+        if let Some(caption) = &self.caption {
+            writeln!(f, "{}", caption)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The sequence of frames emitted by a solution's visualization.
+#[derive(Debug, Clone, Default)]
+pub struct Animation {
+    frames: Vec<Frame>,
+}
+
+impl Animation {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, frame: Frame) {
+        self.frames.push(frame);
+    }
+
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frame_from_text_works() {
+        let mut frame = Frame::from_text("#.#\n.@\n###").with_caption("step 1");
+
+        assert_eq!(frame.height(), 3);
+        assert_eq!(frame.width(), 3);
+        assert_eq!(frame.get(1, 1), Some(&Cell::new('@')));
+        assert_eq!(frame.get(1, 2), Some(&Cell::new(' ')));
+        assert_eq!(frame.get(3, 0), None);
+
+        frame.set(1, 2, Cell::new('O').fg(Color::Yellow));
+        frame.set(5, 5, '#');
+
+        assert_eq!(frame.to_string(), "#.#\n.@O\n###\nstep 1\n");
+    }
+}
@@ -0,0 +1,7 @@
+mod export;
+mod frame;
+mod player;
+
+pub use export::*;
+pub use frame::*;
+pub use player::play;
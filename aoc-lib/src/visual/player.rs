@@ -0,0 +1,353 @@
+use super::{Animation, Cell, Color, Frame};
+use crossterm::{
+    cursor,
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    execute, queue,
+    style::{self, Attribute, Print, SetAttribute, SetBackgroundColor, SetForegroundColor},
+    terminal::{self, ClearType, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use std::{
+    io::{self, IsTerminal, Write},
+    time::{Duration, Instant},
+};
+
+const SPEEDS: [u32; 9] = [1, 2, 5, 10, 20, 30, 60, 120, 240];
+const DEFAULT_SPEED: usize = 3;
+const PAN_STEP: usize = 4;
+const HELP: &str = "space play/pause  ←/→ step  +/- speed  g jump  hjkl pan  q quit";
+
+/// Puts the terminal in raw mode on the alternate screen, restoring it when dropped so that a
+/// panicking solution doesn't leave the terminal unusable.
+struct TerminalGuard;
+
+impl TerminalGuard {
+    fn enter() -> io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        execute!(io::stdout(), EnterAlternateScreen, cursor::Hide)?;
+        Ok(Self)
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), cursor::Show, LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+#[derive(Debug)]
+struct Player<'a> {
+    frames: &'a [Frame],
+    index: usize,
+    playing: bool,
+    speed: usize,
+    offset: (usize, usize),
+    jump_input: Option<String>,
+}
+
+impl<'a> Player<'a> {
+    fn new(frames: &'a [Frame]) -> Self {
+        Self {
+            frames,
+            index: 0,
+            playing: true,
+            speed: DEFAULT_SPEED,
+            offset: (0, 0),
+            jump_input: None,
+        }
+    }
+
+    fn frame_interval(&self) -> Duration {
+        Duration::from_secs(1) / SPEEDS[self.speed]
+    }
+
+    fn step(&mut self, forward: bool) {
+        self.index = if forward {
+            (self.index + 1).min(self.frames.len() - 1)
+        } else {
+            self.index.saturating_sub(1)
+        };
+    }
+
+    /// Handles a key press, returning `false` if the player should quit.
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+            return false;
+        }
+
+        if let Some(input) = &mut self.jump_input {
+            match key.code {
+                KeyCode::Char(ch) if ch.is_ascii_digit() => input.push(ch),
+                KeyCode::Backspace => {
+                    input.pop();
+                }
+                KeyCode::Enter => {
+                    if let Ok(n) = input.parse::<usize>() {
+                        self.index = n.clamp(1, self.frames.len()) - 1;
+                        self.playing = false;
+                    }
+                    self.jump_input = None;
+                }
+                KeyCode::Esc => self.jump_input = None,
+                _ => {}
+            }
+
+            return true;
+        }
+
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            KeyCode::Char(' ') => {
+                if self.index == self.frames.len() - 1 {
+                    self.index = 0;
+                }
+                self.playing = !self.playing;
+            }
+            KeyCode::Right | KeyCode::Char('.') => {
+                self.playing = false;
+                self.step(true);
+            }
+            KeyCode::Left | KeyCode::Char(',') => {
+                self.playing = false;
+                self.step(false);
+            }
+            KeyCode::Home => self.index = 0,
+            KeyCode::End => self.index = self.frames.len() - 1,
+            KeyCode::Char('+') | KeyCode::Char('=') => {
+                self.speed = (self.speed + 1).min(SPEEDS.len() - 1)
+            }
+            KeyCode::Char('-') => self.speed = self.speed.saturating_sub(1),
+            KeyCode::Char('g') => self.jump_input = Some(String::new()),
+            KeyCode::Char('h') => self.offset.1 = self.offset.1.saturating_sub(PAN_STEP),
+            KeyCode::Char('l') => self.offset.1 += PAN_STEP,
+            KeyCode::Char('k') => self.offset.0 = self.offset.0.saturating_sub(PAN_STEP),
+            KeyCode::Char('j') => self.offset.0 += PAN_STEP,
+            _ => {}
+        }
+
+        true
+    }
+
+    fn status_line(&self) -> String {
+        if let Some(input) = &self.jump_input {
+            return format!("Jump to frame (1-{}): {}", self.frames.len(), input);
+        }
+
+        format!(
+            "{} {}/{}  {} fps  {}",
+            if self.playing { "▶" } else { "⏸" },
+            self.index + 1,
+            self.frames.len(),
+            SPEEDS[self.speed],
+            HELP
+        )
+    }
+
+    fn draw<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        let (cols, rows) = terminal::size()?;
+        let (cols, rows) = (cols as usize, rows as usize);
+        let view_height = rows.saturating_sub(2);
+        let frame = &self.frames[self.index];
+
+        for (k, row) in frame
+            .rows()
+            .skip(self.offset.0)
+            .take(view_height)
+            .enumerate()
+        {
+            queue!(out, cursor::MoveTo(0, k as u16))?;
+            draw_cells(out, row.iter().skip(self.offset.1).take(cols))?;
+            queue!(out, terminal::Clear(ClearType::UntilNewLine))?;
+        }
+
+        let drawn = frame
+            .height()
+            .saturating_sub(self.offset.0)
+            .min(view_height);
+        queue!(
+            out,
+            cursor::MoveTo(0, drawn as u16),
+            terminal::Clear(ClearType::FromCursorDown),
+            cursor::MoveTo(0, rows.saturating_sub(2) as u16),
+            Print(truncate(frame.caption().unwrap_or(""), cols)),
+            cursor::MoveTo(0, rows.saturating_sub(1) as u16),
+            SetAttribute(Attribute::Reverse),
+            Print(truncate(&self.status_line(), cols)),
+            SetAttribute(Attribute::Reset),
+        )?;
+
+        out.flush()
+    }
+
+    fn run(&mut self) -> io::Result<()> {
+        let mut stdout = io::stdout();
+        let mut next_tick = Instant::now() + self.frame_interval();
+
+        loop {
+            self.draw(&mut stdout)?;
+
+            let timeout = if self.playing {
+                next_tick.saturating_duration_since(Instant::now())
+            } else {
+                Duration::from_secs(60)
+            };
+
+            if event::poll(timeout)? {
+                match event::read()? {
+                    Event::Key(key) if key.kind != KeyEventKind::Release => {
+                        if !self.handle_key(key) {
+                            return Ok(());
+                        }
+                        next_tick = Instant::now() + self.frame_interval();
+                    }
+                    _ => {}
+                }
+            } else if self.playing {
+                self.step(true);
+                self.playing = self.index < self.frames.len() - 1;
+                next_tick += self.frame_interval();
+            }
+        }
+    }
+}
+
+fn to_terminal_color(color: Color) -> style::Color {
+    match color {
+        Color::Black => style::Color::Black,
+        Color::Red => style::Color::Red,
+        Color::Green => style::Color::Green,
+        Color::Yellow => style::Color::Yellow,
+        Color::Blue => style::Color::Blue,
+        Color::Magenta => style::Color::Magenta,
+        Color::Cyan => style::Color::Cyan,
+        Color::White => style::Color::White,
+        Color::Grey => style::Color::DarkGrey,
+        Color::Rgb(r, g, b) => style::Color::Rgb { r, g, b },
+    }
+}
+
+pub(super) fn draw_cells<'a, W: Write, I: Iterator<Item = &'a Cell>>(
+    out: &mut W,
+    cells: I,
+) -> io::Result<()> {
+    let mut style = Cell::default();
+
+    for cell in cells {
+        if (cell.fg, cell.bg, cell.bold) != (style.fg, style.bg, style.bold) {
+            queue!(out, SetAttribute(Attribute::Reset))?;
+
+            if let Some(fg) = cell.fg {
+                queue!(out, SetForegroundColor(to_terminal_color(fg)))?;
+            }
+            if let Some(bg) = cell.bg {
+                queue!(out, SetBackgroundColor(to_terminal_color(bg)))?;
+            }
+            if cell.bold {
+                queue!(out, SetAttribute(Attribute::Bold))?;
+            }
+
+            style = *cell;
+        }
+
+        queue!(out, Print(cell.ch))?;
+    }
+
+    queue!(out, SetAttribute(Attribute::Reset))
+}
+
+fn truncate(s: &str, width: usize) -> String {
+    s.chars().take(width).collect()
+}
+
+/// Plays an animation in the terminal. When stdout isn't a terminal, every frame is printed as
+/// plain text instead.
+pub fn play(animation: &Animation) -> io::Result<()> {
+    if animation.is_empty() {
+        return Ok(());
+    }
+
+    if !io::stdout().is_terminal() {
+        for frame in animation.frames() {
+            println!("{}", frame);
+        }
+        return Ok(());
+    }
+
+    let _guard = TerminalGuard::enter()?;
+    Player::new(animation.frames()).run()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn press(player: &mut Player, code: KeyCode) -> bool {
+        player.handle_key(KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    #[test]
+    fn handle_key_steps_and_changes_speed() {
+        let frames = vec![Frame::from_text("."); 5];
+        let mut player = Player::new(&frames);
+
+        assert!(press(&mut player, KeyCode::Right));
+        assert!(!player.playing);
+        assert_eq!(player.index, 1);
+
+        press(&mut player, KeyCode::End);
+        press(&mut player, KeyCode::Right);
+        assert_eq!(player.index, 4);
+
+        press(&mut player, KeyCode::Char(' '));
+        assert!(player.playing);
+        assert_eq!(player.index, 0);
+
+        press(&mut player, KeyCode::Left);
+        assert_eq!(player.index, 0);
+
+        press(&mut player, KeyCode::Char('+'));
+        assert_eq!(player.speed, DEFAULT_SPEED + 1);
+        for _ in 0..SPEEDS.len() {
+            press(&mut player, KeyCode::Char('-'));
+        }
+        assert_eq!(player.speed, 0);
+        assert_eq!(player.frame_interval(), Duration::from_secs(1));
+
+        assert!(!press(&mut player, KeyCode::Char('q')));
+        assert!(!player.handle_key(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL)));
+    }
+
+    #[test]
+    fn handle_key_jumps_to_frame() {
+        let frames = vec![Frame::from_text("."); 20];
+        let mut player = Player::new(&frames);
+
+        for code in [KeyCode::Char('g'), KeyCode::Char('1'), KeyCode::Char('5')] {
+            press(&mut player, code);
+        }
+        assert_eq!(player.status_line(), "Jump to frame (1-20): 15");
+
+        // Other keys are ignored while typing a frame number.
+        assert!(press(&mut player, KeyCode::Char('q')));
+        press(&mut player, KeyCode::Enter);
+        assert_eq!(player.index, 14);
+        assert!(!player.playing);
+        assert_eq!(player.jump_input, None);
+
+        for code in [
+            KeyCode::Char('g'),
+            KeyCode::Char('9'),
+            KeyCode::Char('9'),
+            KeyCode::Enter,
+        ] {
+            press(&mut player, code);
+        }
+        assert_eq!(player.index, 19);
+
+        for code in [KeyCode::Char('g'), KeyCode::Char('3'), KeyCode::Esc] {
+            press(&mut player, code);
+        }
+        assert_eq!(player.index, 19);
+        assert_eq!(player.jump_input, None);
+    }
+}
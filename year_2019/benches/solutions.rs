@@ -0,0 +1,3 @@
+fn main() {
+    aoc_lib::cli::bench(&year_2019::registry());
+}
@@ -17,18 +17,13 @@ impl Display for IntcodeError {
 
 impl std::error::Error for IntcodeError {}
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub enum IntcodeState {
+    #[default]
     Active,
     Halted,
 }
 
-impl Default for IntcodeState {
-    fn default() -> Self {
-        Self::Active
-    }
-}
-
 #[derive(Debug, Clone, Default)]
 pub struct IntcodeMachine {
     pc: usize,
@@ -35,6 +35,6 @@ impl PuzzleSolution for Day1 {
     }
 }
 
-fn main() {
-    aoc_lib::cli::run_solution::<Day1>(2019, 1);
+pub(crate) fn solution() -> aoc_lib::cli::Solution {
+    aoc_lib::cli::Solution::new::<Day1>(1)
 }
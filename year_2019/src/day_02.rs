@@ -1,4 +1,4 @@
-use aoc_lib::cli::{PuzzleSolution, SolutionError, SolutionResult};
+use aoc_lib::cli::{Progress, PuzzleSolution, SolutionError, SolutionResult};
 use intcode::IntcodeMachine;
 
 struct Day2;
@@ -27,8 +27,12 @@ impl PuzzleSolution for Day2 {
     }
 
     fn part_2(input: &Self::Input) -> SolutionResult<Self::Output> {
+        let progress = Progress::new(Some(100 * 100));
+
         for noun in 0..=99 {
             for verb in 0..=99 {
+                progress.inc();
+
                 let mut machine = IntcodeMachine::new();
                 machine.load_program(input);
                 machine.memory_mut()[1] = noun;
@@ -46,6 +50,6 @@ impl PuzzleSolution for Day2 {
     }
 }
 
-fn main() {
-    aoc_lib::cli::run_solution::<Day2>(2019, 2);
+pub(crate) fn solution() -> aoc_lib::cli::Solution {
+    aoc_lib::cli::Solution::new::<Day2>(2)
 }
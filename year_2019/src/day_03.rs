@@ -0,0 +1,239 @@
+use std::collections::HashMap;
+
+use aoc_lib::{
+    cli::{Alternative, PuzzleSolution, SolutionError, SolutionResult},
+    helper::structs::{
+        distance_along, sweep_crossings, AxisSegment, Direction, Intersection, SparseGrid, Vector2D,
+    },
+};
+
+#[derive(Debug, Clone)]
+struct Instruction(i32, Direction);
+
+impl From<&str> for Instruction {
+    fn from(s: &str) -> Self {
+        let distance = s[1..].parse().unwrap();
+
+        let direction = s
+            .chars()
+            .next()
+            .and_then(Direction::from_char)
+            .expect("invalid step direction");
+
+        Self(distance, direction)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct Pipe {
+    x: isize,
+    y: isize,
+    steps_taken: u32,
+    /// The number of steps taken to first reach each position.
+    history: SparseGrid<u32, Vector2D<isize>>,
+}
+
+impl Pipe {
+    fn position(&self) -> Vector2D<isize> {
+        Vector2D::new(self.x, self.y)
+    }
+
+    fn log_position(&mut self) {
+        let steps_taken = self.steps_taken;
+        self.history
+            .get_or_insert_with(self.position(), || steps_taken);
+    }
+
+    fn step(&mut self, direction: Direction) {
+        match direction {
+            Direction::Up => self.y -= 1,
+            Direction::Down => self.y += 1,
+            Direction::Left => self.x -= 1,
+            Direction::Right => self.x += 1,
+        }
+
+        self.steps_taken += 1;
+    }
+}
+
+type Wire = Vec<AxisSegment<i64>>;
+
+fn wire_path(instructions: &[Instruction]) -> Wire {
+    AxisSegment::path(
+        Vector2D::default(),
+        instructions
+            .iter()
+            .map(|&Instruction(distance, direction)| (direction, distance as i64)),
+    )
+}
+
+/// Finds the points where parallel segments of the two wires run along each other, which the
+/// sweep line leaves out. Only segments on the same line are compared.
+fn overlap_points(path_a: &[AxisSegment<i64>], path_b: &[AxisSegment<i64>]) -> Vec<Vector2D<i64>> {
+    let line = |segment: &AxisSegment<i64>| match segment.is_horizontal() {
+        true => (true, segment.start().y),
+        false => (false, segment.start().x),
+    };
+
+    let mut lines = HashMap::<_, Vec<_>>::new();
+    for segment in path_a {
+        lines.entry(line(segment)).or_default().push(segment);
+    }
+
+    let mut points = Vec::new();
+
+    for segment_b in path_b {
+        for segment_a in lines.get(&line(segment_b)).into_iter().flatten() {
+            match segment_a.intersection(segment_b) {
+                Intersection::None => {}
+                Intersection::Point(point) => points.push(point),
+                Intersection::Overlap(from, to) => {
+                    let step = (to - from).primitive();
+                    let len = (to - from).manhattan_length();
+                    points.extend((0..=len).map(|k| from + step * k));
+                }
+            }
+        }
+    }
+
+    points
+}
+
+/// Finds where the wires meet with a sweep line over their segments, instead of walking every
+/// cell, along with the points where they run along each other.
+fn wire_crossings(
+    (instructions_a, instructions_b): &(Vec<Instruction>, Vec<Instruction>),
+) -> (Wire, Wire, Vec<Vector2D<i64>>) {
+    let path_a = wire_path(instructions_a);
+    let path_b = wire_path(instructions_b);
+    let segments = [path_a.as_slice(), path_b.as_slice()].concat();
+
+    let crossings = sweep_crossings(&segments)
+        .into_iter()
+        .filter(|crossing| {
+            (crossing.horizontal < path_a.len()) != (crossing.vertical < path_a.len())
+        })
+        .map(|crossing| crossing.point)
+        .chain(overlap_points(&path_a, &path_b))
+        .filter(|&point| point != Vector2D::default())
+        .collect();
+
+    (path_a, path_b, crossings)
+}
+
+struct Day3;
+
+impl PuzzleSolution for Day3 {
+    type Input = (Vec<Instruction>, Vec<Instruction>);
+    type Output = u32;
+
+    fn parse_input(raw_input: String) -> Self::Input {
+        let mut pipe_steps = raw_input
+            .lines()
+            .map(|line| line.split(",").map(Instruction::from).collect::<Vec<_>>());
+
+        let first = pipe_steps.next().unwrap();
+        let second = pipe_steps.next().unwrap();
+        (first, second)
+    }
+
+    fn part_1((instructions_a, instructions_b): &Self::Input) -> SolutionResult<Self::Output> {
+        let mut pipe_a = Pipe::default();
+        let mut pipe_b = Pipe::default();
+
+        let mut intersections = Vec::new();
+
+        pipe_a.log_position();
+
+        for Instruction(distance, direction) in instructions_a {
+            for _ in 0..*distance {
+                pipe_a.step(*direction);
+                pipe_a.log_position();
+            }
+        }
+
+        for Instruction(distance, direction) in instructions_b {
+            for _ in 0..*distance {
+                pipe_b.step(*direction);
+
+                // The wires both start at the central port, which isn't a crossing.
+                if pipe_b.position() == Vector2D::default() {
+                    continue;
+                }
+
+                if pipe_a.history.contains(pipe_b.position()) {
+                    intersections.push((pipe_b.x, pipe_b.y));
+                }
+            }
+        }
+
+        intersections
+            .iter()
+            .map(|(x, y)| (x.abs() + y.abs()) as u32)
+            .min()
+            .ok_or(SolutionError::BadInput)
+    }
+    fn part_2((instructions_a, instructions_b): &Self::Input) -> SolutionResult<Self::Output> {
+        let mut pipe_a = Pipe::default();
+        let mut pipe_b = Pipe::default();
+        let mut intersections = Vec::new();
+
+        pipe_a.log_position();
+
+        for Instruction(distance, direction) in instructions_a {
+            for _ in 0..*distance {
+                pipe_a.step(*direction);
+                pipe_a.log_position();
+            }
+        }
+
+        for Instruction(distance, direction) in instructions_b {
+            for _ in 0..*distance {
+                pipe_b.step(*direction);
+
+                if pipe_b.position() == Vector2D::default() {
+                    continue;
+                }
+
+                if let Some(steps_a_taken) = pipe_a.history.get(pipe_b.position()) {
+                    intersections.push(steps_a_taken + pipe_b.steps_taken);
+                }
+            }
+        }
+
+        intersections
+            .into_iter()
+            .min()
+            .ok_or(SolutionError::BadInput)
+    }
+
+    fn alternatives() -> Vec<Alternative<Self::Input, Self::Output>> {
+        vec![
+            Alternative::new("segments", 1, |input| {
+                let (_, _, crossings) = wire_crossings(input);
+
+                crossings
+                    .iter()
+                    .map(|point| point.manhattan_length() as u32)
+                    .min()
+                    .ok_or(SolutionError::BadInput)
+            }),
+            Alternative::new("segments", 2, |input| {
+                let (path_a, path_b, crossings) = wire_crossings(input);
+
+                crossings
+                    .iter()
+                    .filter_map(|&point| {
+                        Some(distance_along(&path_a, point)? + distance_along(&path_b, point)?)
+                    })
+                    .min()
+                    .map(|steps| steps as u32)
+                    .ok_or(SolutionError::BadInput)
+            }),
+        ]
+    }
+}
+
+pub(crate) fn solution() -> aoc_lib::cli::Solution {
+    aoc_lib::cli::Solution::new::<Day3>(3)
+}
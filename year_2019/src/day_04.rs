@@ -26,7 +26,6 @@ impl PuzzleSolution for Day4 {
     fn part_1(range: &Self::Input) -> SolutionResult<Self::Output> {
         let count = range
             .clone()
-            .into_iter()
             .filter(|passwd| {
                 let windows = digits(*passwd)
                     .windows(2)
@@ -42,7 +41,6 @@ impl PuzzleSolution for Day4 {
     fn part_2(range: &Self::Input) -> SolutionResult<Self::Output> {
         let count = range
             .clone()
-            .into_iter()
             .filter(|passwd| {
                 let windows = digits(*passwd)
                     .windows(2)
@@ -61,6 +59,6 @@ impl PuzzleSolution for Day4 {
     }
 }
 
-fn main() {
-    aoc_lib::cli::run_solution::<Day4>(2019, 4);
+pub(crate) fn solution() -> aoc_lib::cli::Solution {
+    aoc_lib::cli::Solution::new::<Day4>(4)
 }
@@ -14,11 +14,11 @@ impl PuzzleSolution for Day5 {
             .collect()
     }
 
-    fn part_1(input: &Self::Input) -> SolutionResult<Self::Output> {
+    fn part_1(_input: &Self::Input) -> SolutionResult<Self::Output> {
         todo!()
     }
 }
 
-fn main() {
-    aoc_lib::cli::run_solution::<Day5>(2019, 5);
+pub(crate) fn solution() -> aoc_lib::cli::Solution {
+    aoc_lib::cli::Solution::new::<Day5>(5)
 }
@@ -0,0 +1,16 @@
+mod day_01;
+mod day_02;
+mod day_03;
+mod day_04;
+mod day_05;
+
+use aoc_lib::cli::Registry;
+
+pub fn registry() -> Registry {
+    Registry::new(2019)
+        .with_solution(day_01::solution())
+        .with_solution(day_02::solution())
+        .with_solution(day_03::solution())
+        .with_solution(day_04::solution())
+        .with_solution(day_05::solution())
+}
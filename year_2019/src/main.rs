@@ -0,0 +1,3 @@
+fn main() {
+    aoc_lib::cli::run(year_2019::registry());
+}
@@ -0,0 +1,57 @@
+use aoc_lib::cli::with_params;
+use std::fs;
+
+/// Solves a day against its example in `tests/examples`, checking the answers that are given and
+/// that the alternative implementations of each part agree with it.
+fn check(day: u32, params: &[(&str, &str)], expected: [Option<&str>; 2]) {
+    check_example(day, &format!("day_{:02}", day), params, expected);
+}
+
+/// Like [`check`], for one of the extra examples of a day, read from `tests/examples/<name>.txt`.
+fn check_example(day: u32, name: &str, params: &[(&str, &str)], expected: [Option<&str>; 2]) {
+    let registry = year_2019::registry();
+    let solution = registry.get(day).expect("day is not registered");
+
+    let path = format!("{}/tests/examples/{}.txt", env!("CARGO_MANIFEST_DIR"), name);
+    let raw_input = fs::read_to_string(path).expect("could not read example");
+
+    with_params(params, || {
+        let input = solution.parse(raw_input);
+
+        for (part, expected) in (1..).zip(expected) {
+            if let Some(expected) = expected {
+                let answer = solution.part(&input, part).unwrap();
+                assert_eq!(answer.to_string(), expected, "day {} part {}", day, part);
+            }
+
+            for name in solution.alternatives(part) {
+                let answer = solution.alternative(&input, part, name).unwrap().unwrap();
+                let expected = solution.part(&input, part).unwrap();
+                assert_eq!(answer, expected, "day {} part {} ({})", day, part, name);
+            }
+        }
+    });
+}
+
+#[test]
+fn day_01() {
+    check(1, &[], [Some("34241"), Some("51316")]);
+}
+
+#[test]
+fn day_03() {
+    check(3, &[], [Some("6"), Some("30")]);
+}
+
+/// The closest point is inside a stretch where the wires run along each other, past crossings
+/// whose coordinates have the same sign.
+#[test]
+fn day_03_overlap() {
+    check_example(3, "day_03_overlap", &[], [Some("2"), Some("10")]);
+}
+
+/// The second wire passes back through the central port, which doesn't count as a crossing.
+#[test]
+fn day_03_origin() {
+    check_example(3, "day_03_origin", &[], [Some("6"), Some("34")]);
+}
@@ -13,7 +13,6 @@ impl PuzzleSolution for Day1 {
             .lines()
             .map(|line| {
                 let parts = line
-                    .trim()
                     .split_whitespace()
                     .map(|n| n.parse::<i32>().unwrap())
                     .collect::<Vec<_>>();
@@ -30,7 +29,7 @@ impl PuzzleSolution for Day1 {
 
         let sum = left
             .into_iter()
-            .zip(right.into_iter())
+            .zip(right)
             .map(|(a, b)| a.abs_diff(b) as usize)
             .sum::<usize>();
         Ok(sum)
@@ -45,13 +44,13 @@ impl PuzzleSolution for Day1 {
         }
 
         let sum = left
-            .into_iter()
+            .iter()
             .map(|&l| (l as usize) * freqs.get(&l).unwrap_or(&0))
             .sum::<usize>();
         Ok(sum)
     }
 }
 
-fn main() {
-    aoc_lib::cli::run_solution::<Day1>(2024, 1);
+pub(crate) fn solution() -> aoc_lib::cli::Solution {
+    aoc_lib::cli::Solution::new::<Day1>(1)
 }
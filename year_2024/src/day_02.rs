@@ -48,6 +48,6 @@ impl PuzzleSolution for Day2 {
     }
 }
 
-fn main() {
-    aoc_lib::cli::run_solution::<Day2>(2024, 2);
+pub(crate) fn solution() -> aoc_lib::cli::Solution {
+    aoc_lib::cli::Solution::new::<Day2>(2)
 }
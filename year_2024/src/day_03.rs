@@ -89,6 +89,6 @@ impl PuzzleSolution for Day3 {
     }
 }
 
-fn main() {
-    aoc_lib::cli::run_solution::<Day3>(2024, 3);
+pub(crate) fn solution() -> aoc_lib::cli::Solution {
+    aoc_lib::cli::Solution::new::<Day3>(3)
 }
@@ -0,0 +1,85 @@
+use aoc_lib::{
+    cli::{PuzzleSolution, SolutionResult},
+    helper::structs::{Direction8, Index2D, DIRECTIONS_8},
+};
+
+const SEARCH: [char; 4] = ['X', 'M', 'A', 'S'];
+
+/// Checks whether the search word starts at a cell and continues in a direction.
+fn search_from(
+    grid: &[Vec<char>],
+    search: &[char],
+    start: Index2D<usize>,
+    direction: Direction8,
+) -> bool {
+    let offset = direction.offset::<isize>();
+    let start = start.to_signed();
+
+    search.iter().enumerate().all(|(k, &search_ch)| {
+        let k = k as isize;
+        let pos = Index2D::new(start.i + offset.i * k, start.j + offset.j * k);
+
+        pos.to_unsigned()
+            .and_then(|pos| grid.get(pos.i)?.get(pos.j))
+            == Some(&search_ch)
+    })
+}
+
+struct Day4;
+
+impl PuzzleSolution for Day4 {
+    type Input = Vec<Vec<char>>;
+    type Output = usize;
+
+    fn parse_input(raw_input: String) -> Self::Input {
+        raw_input
+            .trim()
+            .lines()
+            .map(|line| line.chars().collect())
+            .collect()
+    }
+
+    fn part_1(grid: &Self::Input) -> SolutionResult<Self::Output> {
+        let mut count = 0;
+
+        for i in 0..grid.len() {
+            for j in 0..grid[i].len() {
+                count += DIRECTIONS_8
+                    .iter()
+                    .filter(|&&direction| search_from(grid, &SEARCH, Index2D::new(i, j), direction))
+                    .count();
+            }
+        }
+
+        Ok(count)
+    }
+
+    fn part_2(grid: &Self::Input) -> SolutionResult<Self::Output> {
+        let mut count = 0;
+
+        for i in 1..(grid.len() - 1) {
+            for j in 1..(grid[i].len() - 1) {
+                if grid[i][j] != 'A' {
+                    continue;
+                }
+
+                let ul = grid[i - 1][j - 1];
+                let ur = grid[i - 1][j + 1];
+                let dr = grid[i + 1][j + 1];
+                let dl = grid[i + 1][j - 1];
+
+                if ((ur == 'M' && dl == 'S') || (ur == 'S' && dl == 'M'))
+                    && ((ul == 'M' && dr == 'S') || (ul == 'S' && dr == 'M'))
+                {
+                    count += 1;
+                }
+            }
+        }
+
+        Ok(count)
+    }
+}
+
+pub(crate) fn solution() -> aoc_lib::cli::Solution {
+    aoc_lib::cli::Solution::new::<Day4>(4)
+}
@@ -96,7 +96,7 @@ impl PuzzleSolution for Day5 {
         let mut to_fix = updates
             .iter()
             .filter(|u| rules.iter().any(|rule| !u.satisfies_rule(rule)))
-            .map(|u| u.clone())
+            .cloned()
             .collect::<Vec<_>>();
 
         for update in &mut to_fix {
@@ -108,6 +108,6 @@ impl PuzzleSolution for Day5 {
     }
 }
 
-fn main() {
-    aoc_lib::cli::run_solution::<Day5>(2024, 5);
+pub(crate) fn solution() -> aoc_lib::cli::Solution {
+    aoc_lib::cli::Solution::new::<Day5>(5)
 }
@@ -0,0 +1,199 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_lib::{
+    cli::{Progress, PuzzleSolution, SolutionResult},
+    helper::structs::{Direction, Index2D},
+    visual::{Animation, Cell as FrameCell, Color, Frame},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Empty,
+    Wall,
+}
+
+type Point2D = Index2D<usize>;
+
+#[derive(Debug, Clone)]
+struct Guard {
+    position: Point2D,
+    direction: Direction,
+}
+
+impl Guard {
+    /// Returns the position in front of the guard, or `None` if they are about to leave the grid.
+    fn ahead(&self, grid: &[Vec<Cell>]) -> Option<Point2D> {
+        self.position
+            .step(self.direction)
+            .filter(|pos| pos.i < grid.len() && pos.j < grid[0].len())
+    }
+}
+
+fn creates_loop(grid: &[Vec<Cell>], mut guard: Guard, added_wall: Point2D) -> bool {
+    let mut seen_states = HashMap::<Point2D, HashSet<Direction>>::new();
+
+    loop {
+        let entry = seen_states.entry(guard.position).or_default();
+
+        if !entry.insert(guard.direction) {
+            // Loop found
+            return true;
+        }
+
+        let Some(new_position) = guard.ahead(grid) else {
+            return false;
+        };
+
+        if let Cell::Wall = grid[new_position.i][new_position.j] {
+            guard.direction = guard.direction.rotated_clockwise();
+        } else if new_position == added_wall {
+            guard.direction = guard.direction.rotated_clockwise();
+        } else {
+            guard.position = new_position;
+        }
+    }
+}
+
+fn patrol<F>(grid: &[Vec<Cell>], mut guard: Guard, mut on_move: F) -> HashSet<Point2D>
+where
+    F: FnMut(&Guard, &HashSet<Point2D>),
+{
+    let mut seen_positions = HashSet::new();
+
+    loop {
+        seen_positions.insert(guard.position);
+        on_move(&guard, &seen_positions);
+
+        let Some(new_position) = guard.ahead(grid) else {
+            break;
+        };
+
+        if let Cell::Wall = grid[new_position.i][new_position.j] {
+            guard.direction = guard.direction.rotated_clockwise();
+        } else {
+            guard.position = new_position;
+        }
+    }
+
+    seen_positions
+}
+
+fn render_patrol(grid: &[Vec<Cell>], guard: &Guard, seen_positions: &HashSet<Point2D>) -> Frame {
+    let mut frame = Frame::new(grid.len(), grid[0].len());
+
+    for (i, row) in grid.iter().enumerate() {
+        for (j, cell) in row.iter().enumerate() {
+            let frame_cell = match cell {
+                Cell::Wall => FrameCell::new('#').fg(Color::Grey),
+                Cell::Empty if seen_positions.contains(&Point2D::new(i, j)) => {
+                    FrameCell::new('X').fg(Color::Cyan)
+                }
+                Cell::Empty => FrameCell::new('.').fg(Color::Grey),
+            };
+
+            frame.set(i, j, frame_cell);
+        }
+    }
+
+    let guard_cell = FrameCell::new(guard.direction.to_char())
+        .fg(Color::Red)
+        .bold();
+    frame.set(guard.position.i, guard.position.j, guard_cell);
+    frame.set_caption(format!("{} positions visited", seen_positions.len()));
+    frame
+}
+
+struct Day6;
+
+impl PuzzleSolution for Day6 {
+    type Input = (Vec<Vec<Cell>>, Guard);
+    type Output = usize;
+
+    fn parse_input(input: String) -> Self::Input {
+        let mut guard = None;
+
+        let cells = input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(j, ch)| match ch {
+                        '#' => Cell::Wall,
+                        '.' => Cell::Empty,
+                        _ => {
+                            guard = Some(Guard {
+                                position: Point2D::new(i, j),
+                                direction: Direction::from_char(ch)
+                                    .expect("invalid guard character"),
+                            });
+                            Cell::Empty
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        (cells, guard.unwrap())
+    }
+
+    fn part_1((grid, guard): &Self::Input) -> SolutionResult<Self::Output> {
+        let seen_positions = patrol(grid, guard.clone(), |_, _| {});
+        Ok(seen_positions.len())
+    }
+
+    fn part_2((grid, initial_guard): &Self::Input) -> SolutionResult<Self::Output> {
+        let mut steps = 0;
+        patrol(grid, initial_guard.clone(), |_, _| steps += 1);
+
+        let progress = Progress::new(Some(steps));
+        let mut guard = initial_guard.clone();
+        let mut added_walls = HashSet::new();
+
+        loop {
+            progress.inc();
+
+            let Some(new_position) = guard.ahead(grid) else {
+                break;
+            };
+
+            if let Cell::Wall = grid[new_position.i][new_position.j] {
+                guard.direction = guard.direction.rotated_clockwise();
+            } else {
+                guard.position = new_position;
+
+                if creates_loop(grid, initial_guard.clone(), new_position) {
+                    added_walls.insert(new_position);
+                }
+            }
+        }
+
+        added_walls.remove(&initial_guard.position);
+        Ok(added_walls.len())
+    }
+
+    fn visualize((grid, guard): &Self::Input, animation: &mut Animation) -> SolutionResult<()> {
+        let mut last_guard: Option<Guard> = None;
+
+        let seen_positions = patrol(grid, guard.clone(), |guard, seen_positions| {
+            if last_guard
+                .as_ref()
+                .is_none_or(|last| last.direction != guard.direction)
+            {
+                animation.push(render_patrol(grid, guard, seen_positions));
+            }
+
+            last_guard = Some(guard.clone());
+        });
+
+        if let Some(guard) = last_guard {
+            animation.push(render_patrol(grid, &guard, &seen_positions));
+        }
+
+        Ok(())
+    }
+}
+
+pub(crate) fn solution() -> aoc_lib::cli::Solution {
+    aoc_lib::cli::Solution::new::<Day6>(6)
+}
@@ -1,4 +1,4 @@
-use aoc_lib::cli::{PuzzleSolution, SolutionResult};
+use aoc_lib::cli::{Alternative, Generated, InputGenerator, PuzzleSolution, Rng, SolutionResult};
 
 fn concat_nums(left: u64, right: u64) -> u64 {
     let mut str = left.to_string();
@@ -6,6 +6,31 @@ fn concat_nums(left: u64, right: u64) -> u64 {
     str.parse().unwrap()
 }
 
+/// Undoes a concatenation, returning what is left of `n` once `right` is cut off its end.
+fn strip_concat(n: u64, right: u64) -> Option<u64> {
+    let pow = 10_u64.pow(right.checked_ilog10().unwrap_or(0) + 1);
+    (n % pow == right).then_some(n / pow)
+}
+
+/// Checks whether `params` can make `target` by undoing the operators from the last parameter
+/// back, which rules out most operators early: e.g. a product has to divide the target.
+fn is_solvable_backwards(target: u64, params: &[u64], concat: bool) -> bool {
+    match params {
+        [] => false,
+        [n] => target == *n,
+        [rest @ .., last] => {
+            (target >= *last && is_solvable_backwards(target - last, rest, concat))
+                || (*last == 0 && target == 0)
+                || (*last != 0
+                    && target.is_multiple_of(*last)
+                    && is_solvable_backwards(target / last, rest, concat))
+                || (concat
+                    && strip_concat(target, *last)
+                        .is_some_and(|target| is_solvable_backwards(target, rest, concat)))
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 struct Equation {
     test_value: u64,
@@ -88,6 +113,14 @@ impl Equation {
     }
 }
 
+fn sum_solvable_backwards(equations: &[Equation], concat: bool) -> u64 {
+    equations
+        .iter()
+        .filter(|eq| is_solvable_backwards(eq.test_value, &eq.params, concat))
+        .map(|eq| eq.test_value)
+        .sum()
+}
+
 struct Day7;
 
 impl PuzzleSolution for Day7 {
@@ -114,8 +147,49 @@ impl PuzzleSolution for Day7 {
             .sum();
         Ok(count)
     }
+
+    fn alternatives() -> Vec<Alternative<Self::Input, Self::Output>> {
+        vec![
+            Alternative::new("backwards", 1, |input| {
+                Ok(sum_solvable_backwards(input, false))
+            }),
+            Alternative::new("backwards", 2, |input| {
+                Ok(sum_solvable_backwards(input, true))
+            }),
+        ]
+    }
+}
+
+impl InputGenerator for Day7 {
+    fn generate(rng: &mut Rng) -> Generated {
+        let equations = (0..rng.usize(1..10))
+            .map(|_| {
+                let params = (0..rng.usize(1..7))
+                    .map(|_| rng.u64(0..20))
+                    .collect::<Vec<_>>();
+
+                // Half of the test values are made with random operators, so that they're solvable.
+                let test_value = if rng.bool() {
+                    params[1..]
+                        .iter()
+                        .fold(params[0], |acc, &n| match rng.u8(0..3) {
+                            0 => acc + n,
+                            1 => acc * n,
+                            _ => concat_nums(acc, n),
+                        })
+                } else {
+                    rng.u64(0..10_000)
+                };
+
+                let params = params.iter().map(u64::to_string).collect::<Vec<_>>();
+                format!("{}: {}\n", test_value, params.join(" "))
+            })
+            .collect::<String>();
+
+        Generated::new(equations)
+    }
 }
 
-fn main() {
-    aoc_lib::cli::run_solution::<Day7>(2024, 7);
+pub(crate) fn solution() -> aoc_lib::cli::Solution {
+    aoc_lib::cli::Solution::new_generated::<Day7>(7)
 }
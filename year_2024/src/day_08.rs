@@ -44,12 +44,9 @@ impl PuzzleSolution for Day8 {
         let AntennaMap { size, antennas } = input;
         let mut antinodes = HashSet::new();
 
-        for (_, antennas) in antennas {
-            for i in 0..antennas.len() {
-                let a = antennas[i];
-
-                for j in (i + 1)..antennas.len() {
-                    let b = antennas[j];
+        for antennas in antennas.values() {
+            for (i, &a) in antennas.iter().enumerate() {
+                for &b in &antennas[(i + 1)..] {
                     let diff = b - a;
 
                     let antinode_a = a - diff;
@@ -72,12 +69,9 @@ impl PuzzleSolution for Day8 {
     fn part_2(map: &Self::Input) -> SolutionResult<Self::Output> {
         let mut antinodes = HashSet::new();
 
-        for (_, antennas) in &map.antennas {
-            for i in 0..antennas.len() {
-                let a = antennas[i];
-
-                for j in (i + 1)..antennas.len() {
-                    let b = antennas[j];
+        for antennas in map.antennas.values() {
+            for (i, &a) in antennas.iter().enumerate() {
+                for &b in &antennas[(i + 1)..] {
                     let step = b - a;
 
                     let mut pos = b;
@@ -101,6 +95,6 @@ impl PuzzleSolution for Day8 {
     }
 }
 
-fn main() {
-    aoc_lib::cli::run_solution::<Day8>(2024, 8);
+pub(crate) fn solution() -> aoc_lib::cli::Solution {
+    aoc_lib::cli::Solution::new::<Day8>(8)
 }
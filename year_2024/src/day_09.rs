@@ -1,11 +1,7 @@
-use aoc_lib::cli::{PuzzleSolution, SolutionResult};
+use aoc_lib::cli::{OwnedSolution, SolutionResult};
 
 fn checksum(filesystem: Vec<usize>) -> usize {
-    filesystem
-        .iter()
-        .enumerate()
-        .map(|(i, &n)| i * (n as usize))
-        .sum()
+    filesystem.iter().enumerate().map(|(i, &n)| i * n).sum()
 }
 
 #[derive(Debug, Clone)]
@@ -16,7 +12,7 @@ enum Segment {
 
 struct Day9;
 
-impl PuzzleSolution for Day9 {
+impl OwnedSolution for Day9 {
     type Input = Vec<u8>;
     type Output = usize;
 
@@ -28,8 +24,7 @@ impl PuzzleSolution for Day9 {
             .collect()
     }
 
-    fn part_1(input: &Self::Input) -> SolutionResult<Self::Output> {
-        let mut input = input.clone();
+    fn part_1(mut input: Self::Input) -> SolutionResult<Self::Output> {
         let mut mem = Vec::new();
 
         let mut left_id = 0;
@@ -67,7 +62,7 @@ impl PuzzleSolution for Day9 {
         Ok(checksum(mem))
     }
 
-    fn part_2(input: &Self::Input) -> SolutionResult<Self::Output> {
+    fn part_2(input: Self::Input) -> SolutionResult<Self::Output> {
         let mut mem = Vec::new();
 
         for (i, &n) in input.iter().enumerate() {
@@ -115,6 +110,6 @@ impl PuzzleSolution for Day9 {
     }
 }
 
-fn main() {
-    aoc_lib::cli::run_solution::<Day9>(2024, 9);
+pub(crate) fn solution() -> aoc_lib::cli::Solution {
+    aoc_lib::cli::Solution::new_owned::<Day9>(9)
 }
@@ -0,0 +1,71 @@
+use std::collections::HashSet;
+
+use aoc_lib::{
+    cli::{PuzzleSolution, SolutionResult},
+    helper::structs::{Grid, Index2D},
+};
+
+/// Returns the neighbors of a position that are one step higher.
+fn uphill(grid: &Grid<u32>, pos: Index2D<usize>) -> impl Iterator<Item = Index2D<usize>> + '_ {
+    let height = grid[pos];
+
+    grid.neighbors(pos)
+        .filter(move |&next| grid[next] == height + 1)
+}
+
+fn trailhead_score(grid: &Grid<u32>, pos: Index2D<usize>, seen_tops: &mut HashSet<Index2D<usize>>) {
+    if grid[pos] == 9 {
+        seen_tops.insert(pos);
+    } else {
+        for next in uphill(grid, pos) {
+            trailhead_score(grid, next, seen_tops);
+        }
+    }
+}
+
+fn trailhead_rating(grid: &Grid<u32>, pos: Index2D<usize>) -> usize {
+    if grid[pos] == 9 {
+        1
+    } else {
+        uphill(grid, pos)
+            .map(|next| trailhead_rating(grid, next))
+            .sum()
+    }
+}
+
+struct Day10;
+
+impl PuzzleSolution for Day10 {
+    type Input = Grid<u32>;
+    type Output = usize;
+
+    fn parse_input(raw_input: String) -> Self::Input {
+        Grid::parse(raw_input.trim(), |ch| ch.to_digit(10).unwrap())
+    }
+
+    fn part_1(grid: &Self::Input) -> SolutionResult<Self::Output> {
+        let sum = grid
+            .find_all(&0)
+            .map(|pos| {
+                let mut seen_tops = HashSet::new();
+                trailhead_score(grid, pos, &mut seen_tops);
+                seen_tops.len()
+            })
+            .sum();
+
+        Ok(sum)
+    }
+
+    fn part_2(grid: &Self::Input) -> SolutionResult<Self::Output> {
+        let sum = grid
+            .find_all(&0)
+            .map(|pos| trailhead_rating(grid, pos))
+            .sum();
+
+        Ok(sum)
+    }
+}
+
+pub(crate) fn solution() -> aoc_lib::cli::Solution {
+    aoc_lib::cli::Solution::new::<Day10>(10)
+}
@@ -62,6 +62,6 @@ impl PuzzleSolution for Day11 {
     }
 }
 
-fn main() {
-    aoc_lib::cli::run_solution::<Day11>(2024, 11);
+pub(crate) fn solution() -> aoc_lib::cli::Solution {
+    aoc_lib::cli::Solution::new::<Day11>(11)
 }
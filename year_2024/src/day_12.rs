@@ -1,47 +1,20 @@
 use std::collections::HashSet;
 
-use aoc_lib::cli::{PuzzleSolution, SolutionResult};
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-enum Direction {
-    Up,
-    Right,
-    Down,
-    Left,
-}
-
-const DIRECTIONS: [Direction; 4] = [
-    Direction::Up,
-    Direction::Right,
-    Direction::Down,
-    Direction::Left,
-];
-
-impl Direction {
-    fn rotated_clockwise(&self) -> Self {
-        match self {
-            Self::Up => Self::Right,
-            Self::Right => Self::Down,
-            Self::Down => Self::Left,
-            Self::Left => Self::Up,
-        }
-    }
-}
+use aoc_lib::{
+    cli::{PuzzleSolution, SolutionResult},
+    helper::structs::{Direction, DIRECTIONS},
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 struct Position(isize, isize);
 
 impl Position {
     fn step(&self, direction: Direction) -> Self {
-        match direction {
-            Direction::Up => Self(self.0 - 1, self.1),
-            Direction::Right => Self(self.0, self.1 + 1),
-            Direction::Down => Self(self.0 + 1, self.1),
-            Direction::Left => Self(self.0, self.1 - 1),
-        }
+        let offset = direction.offset::<isize>();
+        Self(self.0 + offset.i, self.1 + offset.j)
     }
 
-    fn is_within<T>(&self, grid: &Vec<Vec<T>>) -> bool {
+    fn is_within<T>(&self, grid: &[Vec<T>]) -> bool {
         (0..(grid.len() as isize)).contains(&self.0)
             && (0..(grid[0].len() as isize)).contains(&self.1)
     }
@@ -78,15 +51,13 @@ impl PuzzleSolution for Day12 {
                 let mut perimeter = 0;
                 let mut stack = vec![root_pos];
 
-                while !stack.is_empty() {
-                    let pos = stack.pop().unwrap();
-
+                while let Some(pos) = stack.pop() {
                     area += 1;
 
                     for direction in DIRECTIONS {
                         let npos = pos.step(direction);
 
-                        if npos.is_within(&grid)
+                        if npos.is_within(grid)
                             && grid[pos.0 as usize][pos.1 as usize]
                                 == grid[npos.0 as usize][npos.1 as usize]
                         {
@@ -124,8 +95,7 @@ impl PuzzleSolution for Day12 {
                 let mut sides = 0;
                 let mut stack = vec![root_pos];
 
-                while !stack.is_empty() {
-                    let pos = stack.pop().unwrap();
+                while let Some(pos) = stack.pop() {
                     let pos_ch = grid[pos.0 as usize][pos.1 as usize];
 
                     area += 1;
@@ -133,28 +103,28 @@ impl PuzzleSolution for Day12 {
                     for direction in DIRECTIONS {
                         let npos = pos.step(direction);
 
-                        if npos.is_within(&grid) && pos_ch == grid[npos.0 as usize][npos.1 as usize]
+                        if npos.is_within(grid)
+                            && pos_ch == grid[npos.0 as usize][npos.1 as usize]
+                            && visited.insert(npos)
                         {
-                            if visited.insert(npos) {
-                                stack.push(npos);
-                            }
+                            stack.push(npos);
                         }
 
                         let right = direction.rotated_clockwise();
                         let rpos = pos.step(right);
 
-                        if (!npos.is_within(&grid)
+                        if (!npos.is_within(grid)
                             || grid[npos.0 as usize][npos.1 as usize] != pos_ch)
-                            && (!rpos.is_within(&grid)
+                            && (!rpos.is_within(grid)
                                 || grid[rpos.0 as usize][rpos.1 as usize] != pos_ch)
                         {
                             // Convex corner
                             sides += 1;
                         }
 
-                        if npos.is_within(&grid)
+                        if npos.is_within(grid)
                             && grid[npos.0 as usize][npos.1 as usize] == pos_ch
-                            && rpos.is_within(&grid)
+                            && rpos.is_within(grid)
                             && grid[rpos.0 as usize][rpos.1 as usize] == pos_ch
                         {
                             // Possible concave corner
@@ -177,6 +147,6 @@ impl PuzzleSolution for Day12 {
     }
 }
 
-fn main() {
-    aoc_lib::cli::run_solution::<Day12>(2024, 12);
+pub(crate) fn solution() -> aoc_lib::cli::Solution {
+    aoc_lib::cli::Solution::new::<Day12>(12)
 }
@@ -58,9 +58,9 @@ impl Machine {
     }
 }
 
-struct Day12;
+struct Day13;
 
-impl PuzzleSolution for Day12 {
+impl PuzzleSolution for Day13 {
     type Input = Vec<Machine>;
     type Output = i128;
 
@@ -116,6 +116,6 @@ impl PuzzleSolution for Day12 {
     }
 }
 
-fn main() {
-    aoc_lib::cli::run_solution::<Day12>(2024, 13);
+pub(crate) fn solution() -> aoc_lib::cli::Solution {
+    aoc_lib::cli::Solution::new::<Day13>(13)
 }
@@ -0,0 +1,185 @@
+use std::ops::Range;
+
+use aoc_lib::{
+    cli::{
+        Alternative, Generated, InputGenerator, Param, ParamInfo, PuzzleSolution, Rng,
+        SolutionResult,
+    },
+    helper::structs::{Torus, Vector2D},
+    visual::{Animation, Cell, Color, Frame},
+};
+
+const WIDTH: Param<i32> = Param::new("width", 101);
+const HEIGHT: Param<i32> = Param::new("height", 103);
+const CANDIDATE_FRAMES: usize = 100;
+const STEPS: i32 = 100;
+
+fn parse_vector(s: &str) -> Vector2D<i32> {
+    let (x, y) = s.split_once(",").unwrap();
+    Vector2D {
+        x: x.parse().unwrap(),
+        y: y.parse().unwrap(),
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Robot {
+    position: Vector2D<i32>,
+    velocity: Vector2D<i32>,
+}
+
+fn count_quadrant(robots: &[Robot], x_range: Range<i32>, y_range: Range<i32>) -> usize {
+    robots
+        .iter()
+        .filter(|robot| x_range.contains(&robot.position.x) && y_range.contains(&robot.position.y))
+        .count()
+}
+
+fn robots_after(robots: &[Robot], steps: i32, width: i32, height: i32) -> Vec<Robot> {
+    robots
+        .iter()
+        .map(|robot| {
+            let position = Torus::new(width, height).advance(robot.position, robot.velocity, steps);
+
+            Robot {
+                position,
+                velocity: robot.velocity,
+            }
+        })
+        .collect()
+}
+
+/// Moves the robots one second at a time, as a reference for [`robots_after`].
+fn robots_after_stepwise(robots: &[Robot], steps: i32, width: i32, height: i32) -> Vec<Robot> {
+    let mut robots = robots.to_vec();
+
+    for _ in 0..steps {
+        robots = robots_after(&robots, 1, width, height);
+    }
+
+    robots
+}
+
+fn safety_factor(robots: &[Robot], width: i32, height: i32) -> usize {
+    let top_left = count_quadrant(robots, 0..(width / 2), 0..(height / 2));
+    let top_right = count_quadrant(robots, (width / 2 + 1)..width, 0..(height / 2));
+    let bottom_left = count_quadrant(robots, 0..(width / 2), (height / 2 + 1)..height);
+    let bottom_right = count_quadrant(robots, (width / 2 + 1)..width, (height / 2 + 1)..height);
+
+    top_left * top_right * bottom_right * bottom_left
+}
+
+fn render_robots(robots: &[Robot], width: i32, height: i32) -> Frame {
+    let mut frame = Frame::new(height as usize, width as usize);
+
+    for robot in robots {
+        let Vector2D { x, y } = robot.position;
+        frame.set(y as usize, x as usize, Cell::new('#').fg(Color::Green));
+    }
+
+    frame
+}
+
+struct Day14;
+
+impl PuzzleSolution for Day14 {
+    type Input = Vec<Robot>;
+    type Output = usize;
+
+    fn parse_input(raw_input: String) -> Self::Input {
+        raw_input
+            .lines()
+            .map(|line| {
+                let (p, v) = line.split_once(" ").unwrap();
+
+                Robot {
+                    position: parse_vector(p.split_once("=").unwrap().1),
+                    velocity: parse_vector(v.split_once("=").unwrap().1),
+                }
+            })
+            .collect::<Vec<_>>()
+    }
+
+    fn part_1(robots: &Self::Input) -> SolutionResult<Self::Output> {
+        let (width, height) = (WIDTH.get(), HEIGHT.get());
+        let robots = robots_after(robots, STEPS, width, height);
+
+        Ok(safety_factor(&robots, width, height))
+    }
+
+    fn part_2(robots: &Self::Input) -> SolutionResult<Self::Output> {
+        let (width, height) = (WIDTH.get(), HEIGHT.get());
+
+        // Robots bunch up to draw the tree, which makes for an unusually low safety factor. The
+        // positions repeat every `width * height` steps, so only those need to be checked.
+        let step = (0..(width * height))
+            .min_by_key(|&step| {
+                safety_factor(&robots_after(robots, step, width, height), width, height)
+            })
+            .unwrap();
+
+        Ok(step as usize)
+    }
+
+    fn params() -> Vec<ParamInfo> {
+        vec![WIDTH.info(), HEIGHT.info()]
+    }
+
+    fn alternatives() -> Vec<Alternative<Self::Input, Self::Output>> {
+        vec![Alternative::new("stepwise", 1, |robots| {
+            let (width, height) = (WIDTH.get(), HEIGHT.get());
+            let robots = robots_after_stepwise(robots, STEPS, width, height);
+
+            Ok(safety_factor(&robots, width, height))
+        })]
+    }
+
+    fn visualize(robots: &Self::Input, animation: &mut Animation) -> SolutionResult<()> {
+        let (width, height) = (WIDTH.get(), HEIGHT.get());
+
+        let mut candidates = (0..(width * height))
+            .map(|step| {
+                let robots = robots_after(robots, step, width, height);
+                (safety_factor(&robots, width, height), step)
+            })
+            .collect::<Vec<_>>();
+
+        candidates.sort_unstable();
+        candidates.truncate(CANDIDATE_FRAMES);
+        candidates.sort_unstable_by_key(|&(_, step)| step);
+
+        for (factor, step) in candidates {
+            let robots = robots_after(robots, step, width, height);
+            let caption = format!("After {} seconds (safety factor {})", step, factor);
+            animation.push(render_robots(&robots, width, height).with_caption(caption));
+        }
+
+        Ok(())
+    }
+}
+
+impl InputGenerator for Day14 {
+    fn generate(rng: &mut Rng) -> Generated {
+        let (width, height) = (rng.i32(1..16), rng.i32(1..16));
+
+        let robots = (0..rng.usize(0..30))
+            .map(|_| {
+                format!(
+                    "p={},{} v={},{}\n",
+                    rng.i32(0..width),
+                    rng.i32(0..height),
+                    rng.i32(-width..=width),
+                    rng.i32(-height..=height)
+                )
+            })
+            .collect::<String>();
+
+        Generated::new(robots)
+            .with_param("width", width)
+            .with_param("height", height)
+    }
+}
+
+pub(crate) fn solution() -> aoc_lib::cli::Solution {
+    aoc_lib::cli::Solution::new_generated::<Day14>(14)
+}
@@ -1,14 +1,12 @@
-use std::{collections::HashSet, hash::Hash};
+use std::collections::HashSet;
 
-use aoc_lib::cli::{PuzzleSolution, SolutionResult};
+use aoc_lib::{
+    cli::{PuzzleSolution, SolutionResult},
+    helper::structs::{Direction, Index2D},
+    visual::{Animation, Cell, Color, Frame},
+};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-enum Direction {
-    Up,
-    Right,
-    Down,
-    Left,
-}
+const MAX_FRAMES: usize = 1000;
 
 #[derive(Debug, Clone)]
 struct Day15Input {
@@ -18,8 +16,75 @@ struct Day15Input {
     steps: Vec<Direction>,
 }
 
+/// Returns the position one step away in a direction. The warehouse is walled in, so this never
+/// leaves the grid.
+fn moved((i, j): (usize, usize), direction: Direction) -> (usize, usize) {
+    let next = Index2D::new(i, j)
+        .step(direction)
+        .expect("moved off the grid");
+    (next.i, next.j)
+}
+
 struct Day15;
 
+#[derive(Debug, Clone)]
+struct WideWarehouse {
+    position: (usize, usize),
+    stones_left: HashSet<(usize, usize)>,
+    walls: HashSet<(usize, usize)>,
+}
+
+impl WideWarehouse {
+    fn new(input: &Day15Input) -> Self {
+        let mut walls = HashSet::new();
+
+        for &wall in &input.walls {
+            walls.insert((wall.0, 2 * wall.1));
+            walls.insert((wall.0, 2 * wall.1 + 1));
+        }
+
+        let position = (input.initial_position.0, 2 * input.initial_position.1);
+        let stones_left = input
+            .stones
+            .iter()
+            .map(|&stone| (stone.0, 2 * stone.1))
+            .collect::<HashSet<_>>();
+
+        Self {
+            position,
+            stones_left,
+            walls,
+        }
+    }
+
+    fn step(&mut self, step: Direction) {
+        let next_pos = moved(self.position, step);
+
+        if try_clearing(next_pos, &mut self.stones_left, &self.walls, step, true) {
+            self.position = next_pos;
+        }
+    }
+
+    fn render(&self) -> Frame {
+        let height = self.walls.iter().map(|wall| wall.0).max().unwrap_or(0) + 1;
+        let width = self.walls.iter().map(|wall| wall.1).max().unwrap_or(0) + 1;
+        let mut frame = Frame::filled(height, width, Cell::new('.').fg(Color::Grey));
+
+        for &(i, j) in &self.walls {
+            frame.set(i, j, Cell::new('#').fg(Color::Blue));
+        }
+
+        for &(i, j) in &self.stones_left {
+            frame.set(i, j, Cell::new('[').fg(Color::Yellow));
+            frame.set(i, j + 1, Cell::new(']').fg(Color::Yellow));
+        }
+
+        let (i, j) = self.position;
+        frame.set(i, j, Cell::new('@').fg(Color::Red).bold());
+        frame
+    }
+}
+
 impl PuzzleSolution for Day15 {
     type Input = Day15Input;
     type Output = usize;
@@ -57,13 +122,7 @@ impl PuzzleSolution for Day15 {
         let steps = steps_str
             .join("")
             .chars()
-            .map(|ch| match ch {
-                '^' => Direction::Up,
-                '>' => Direction::Right,
-                'v' => Direction::Down,
-                '<' => Direction::Left,
-                _ => panic!("invalid step character"),
-            })
+            .map(|ch| Direction::from_char(ch).expect("invalid step character"))
             .collect::<Vec<_>>();
 
         Day15Input {
@@ -79,15 +138,8 @@ impl PuzzleSolution for Day15 {
         let mut pos = input.initial_position;
         let mut stones = input.stones.clone();
 
-        for step in &input.steps {
-            let mut next_pos = pos;
-
-            match step {
-                Direction::Up => next_pos.0 -= 1,
-                Direction::Right => next_pos.1 += 1,
-                Direction::Down => next_pos.0 += 1,
-                Direction::Left => next_pos.1 -= 1,
-            }
+        for &step in &input.steps {
+            let next_pos = moved(pos, step);
 
             if walls.contains(&next_pos) {
                 continue;
@@ -98,12 +150,7 @@ impl PuzzleSolution for Day15 {
                 let mut swap_pos = next_pos;
 
                 let do_swap = loop {
-                    match step {
-                        Direction::Up => swap_pos.0 -= 1,
-                        Direction::Right => swap_pos.1 += 1,
-                        Direction::Down => swap_pos.0 += 1,
-                        Direction::Left => swap_pos.1 -= 1,
-                    }
+                    swap_pos = moved(swap_pos, step);
 
                     if walls.contains(&swap_pos) {
                         break false;
@@ -129,38 +176,36 @@ impl PuzzleSolution for Day15 {
     }
 
     fn part_2(input: &Self::Input) -> SolutionResult<Self::Output> {
-        let mut walls = HashSet::new();
+        let mut warehouse = WideWarehouse::new(input);
 
-        for &wall in &input.walls {
-            walls.insert((wall.0, 2 * wall.1));
-            walls.insert((wall.0, 2 * wall.1 + 1));
+        for &step in &input.steps {
+            warehouse.step(step);
         }
 
-        let mut pos = (input.initial_position.0, 2 * input.initial_position.1);
-        let mut stones_left = input
-            .stones
+        let sum = warehouse
+            .stones_left
             .iter()
-            .map(|&stone| (stone.0, 2 * stone.1))
-            .collect::<HashSet<_>>();
+            .map(|stone| 100 * stone.0 + stone.1)
+            .sum();
+        Ok(sum)
+    }
 
-        for step in &input.steps {
-            let next_pos = match step {
-                Direction::Up => (pos.0 - 1, pos.1),
-                Direction::Right => (pos.0, pos.1 + 1),
-                Direction::Down => (pos.0 + 1, pos.1),
-                Direction::Left => (pos.0, pos.1 - 1),
-            };
+    fn visualize(input: &Self::Input, animation: &mut Animation) -> SolutionResult<()> {
+        let mut warehouse = WideWarehouse::new(input);
+        let stride = input.steps.len().div_ceil(MAX_FRAMES).max(1);
 
-            if try_clearing(next_pos, &mut stones_left, &walls, *step, true) {
-                pos = next_pos;
+        animation.push(warehouse.render().with_caption("Initial state"));
+
+        for (k, &step) in input.steps.iter().enumerate() {
+            warehouse.step(step);
+
+            if (k + 1) % stride == 0 || k + 1 == input.steps.len() {
+                let caption = format!("Move {}/{}: {:?}", k + 1, input.steps.len(), step);
+                animation.push(warehouse.render().with_caption(caption));
             }
         }
 
-        let sum = stones_left
-            .iter()
-            .map(|stone| 100 * stone.0 + stone.1)
-            .sum();
-        Ok(sum)
+        Ok(())
     }
 }
 
@@ -178,7 +223,7 @@ fn try_clearing(
     let Some(stone) = stones_left
         .get(&pos)
         .or_else(|| stones_left.get(&(pos.0, pos.1 - 1)))
-        .map(|s| s.clone())
+        .copied()
     else {
         return true;
     };
@@ -267,6 +312,6 @@ fn try_clearing(
     }
 }
 
-fn main() {
-    aoc_lib::cli::run_solution::<Day15>(2024, 15);
+pub(crate) fn solution() -> aoc_lib::cli::Solution {
+    aoc_lib::cli::Solution::new::<Day15>(15)
 }
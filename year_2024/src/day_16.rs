@@ -0,0 +1,369 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_lib::{
+    cli::{
+        Alternative, Generated, InputGenerator, Rng, SharedSolution, SolutionError, SolutionResult,
+    },
+    helper::structs::{Direction, Index2D, MinPriorityQueue, DIRECTIONS},
+    visual::{Animation, Cell, Color, Frame},
+};
+
+const MAX_FRAMES: usize = 500;
+
+type Point = Index2D<usize>;
+type State = (Point, Direction);
+
+struct Day16;
+
+#[derive(Debug, Clone)]
+struct Day16Input {
+    start: Point,
+    end: Point,
+    walls: HashSet<Point>,
+}
+
+#[derive(Debug, Clone)]
+struct Day16Shared {
+    best_score: usize,
+    /// The lowest score of each state reached while searching for the best path.
+    scores: HashMap<State, usize>,
+}
+
+/// Runs Dijkstra's algorithm over the states of the reindeer, returning the lowest score of every
+/// state reachable from the sources.
+fn state_scores<F, I>(sources: &[State], next: F) -> HashMap<State, usize>
+where
+    F: FnMut(State) -> I,
+    I: IntoIterator<Item = (State, usize)>,
+{
+    state_scores_visiting(sources, next, |_| {})
+}
+
+/// Like [`state_scores`], calling `on_visit` with each state as its lowest score is settled.
+fn state_scores_visiting<F, I, V>(
+    sources: &[State],
+    mut next: F,
+    mut on_visit: V,
+) -> HashMap<State, usize>
+where
+    F: FnMut(State) -> I,
+    I: IntoIterator<Item = (State, usize)>,
+    V: FnMut(State),
+{
+    let mut scores = HashMap::new();
+    let mut queue = MinPriorityQueue::<State, usize>::new();
+
+    for &source in sources {
+        queue.insert(source, 0);
+    }
+
+    while let Some((state, score)) = queue.pop() {
+        scores.insert(state, score);
+        on_visit(state);
+
+        for (next_state, cost) in next(state) {
+            if scores.contains_key(&next_state) {
+                continue;
+            }
+
+            let next_score = score + cost;
+
+            match queue.get_priority_of(&next_state) {
+                Some(&existing_score) if existing_score <= next_score => {}
+                Some(_) => {
+                    queue.update_priority(&next_state, next_score);
+                }
+                None => queue.insert(next_state, next_score),
+            }
+        }
+    }
+
+    scores
+}
+
+/// The cost of entering a tile facing `new_direction`, after facing `direction`.
+fn move_cost(direction: Direction, new_direction: Direction) -> usize {
+    if direction == new_direction {
+        1
+    } else {
+        1001
+    }
+}
+
+/// The states reachable in one move, with their cost: ahead, or turning and moving. The end is
+/// never left.
+fn forward_moves(
+    input: &Day16Input,
+    (pos, direction): State,
+) -> impl Iterator<Item = (State, usize)> + '_ {
+    [
+        direction,
+        direction.rotated_clockwise(),
+        direction.rotated_counter_clockwise(),
+    ]
+    .into_iter()
+    .filter(move |_| pos != input.end)
+    .filter_map(move |new_direction| {
+        let new_pos = pos.step(new_direction)?;
+        let cost = move_cost(direction, new_direction);
+        (!input.walls.contains(&new_pos)).then_some(((new_pos, new_direction), cost))
+    })
+}
+
+/// The lowest scores from the start, searching forwards.
+fn scores_from_start(input: &Day16Input) -> HashMap<State, usize> {
+    state_scores(&[(input.start, Direction::Right)], |state| {
+        forward_moves(input, state)
+    })
+}
+
+/// The lowest scores to the end, searching backwards from it in every direction.
+fn scores_to_end(input: &Day16Input) -> HashMap<State, usize> {
+    let ends = DIRECTIONS.map(|direction| (input.end, direction));
+
+    state_scores(&ends, |(pos, direction)| {
+        let previous_pos = pos
+            .step(direction.backwards())
+            .filter(|previous| *previous != input.end && !input.walls.contains(previous));
+
+        previous_pos.into_iter().flat_map(move |previous_pos| {
+            [
+                direction,
+                direction.rotated_clockwise(),
+                direction.rotated_counter_clockwise(),
+            ]
+            .map(|previous_direction| {
+                let cost = move_cost(previous_direction, direction);
+                ((previous_pos, previous_direction), cost)
+            })
+        })
+    })
+}
+
+fn best_score_of(input: &Day16Input, from_start: &HashMap<State, usize>) -> Option<usize> {
+    DIRECTIONS
+        .iter()
+        .filter_map(|&direction| from_start.get(&(input.end, direction)))
+        .min()
+        .copied()
+}
+
+/// Counts the tiles on a best path from the scores from the start, with a single search back from
+/// the end: a state is on a best path if its scores from both ends add up to the best score.
+fn tiles_on_best_paths(
+    input: &Day16Input,
+    from_start: &HashMap<State, usize>,
+    best_score: usize,
+) -> usize {
+    let to_end = {
+        let _t = aoc_lib::span("search back from the end");
+        scores_to_end(input)
+    };
+
+    let tiles = from_start
+        .iter()
+        .filter(|(state, score)| to_end.get(state).is_some_and(|s| *score + s == best_score))
+        .map(|((pos, _), _)| *pos)
+        .collect::<HashSet<_>>();
+
+    tiles.len()
+}
+
+/// Counts the tiles on a best path by searching for the end again from every state reached.
+fn tiles_on_best_paths_per_state(input: &Day16Input) -> SolutionResult<usize> {
+    let from_start = scores_from_start(input);
+    let best_score = best_score_of(input, &from_start).ok_or(SolutionError::BadInput)?;
+
+    let tiles = from_start
+        .iter()
+        .filter(|&(&state, &score)| {
+            let _t = aoc_lib::span("search from state");
+            let from_state = state_scores(&[state], |state| forward_moves(input, state));
+            best_score_of(input, &from_state).is_some_and(|rest| score + rest == best_score)
+        })
+        .map(|((pos, _), _)| *pos)
+        .collect::<HashSet<_>>();
+
+    Ok(tiles.len())
+}
+
+fn render_maze(input: &Day16Input, visited: &HashSet<Point>) -> Frame {
+    let height = input.walls.iter().map(|wall| wall.i).max().unwrap_or(0) + 1;
+    let width = input.walls.iter().map(|wall| wall.j).max().unwrap_or(0) + 1;
+    let mut frame = Frame::filled(height, width, Cell::new('.').fg(Color::Grey));
+
+    for &Index2D { i, j } in &input.walls {
+        frame.set(i, j, Cell::new('#').fg(Color::Blue));
+    }
+
+    for &Index2D { i, j } in visited {
+        frame.set(i, j, Cell::new('o').fg(Color::Cyan));
+    }
+
+    frame.set(
+        input.start.i,
+        input.start.j,
+        Cell::new('S').fg(Color::Green).bold(),
+    );
+    frame.set(
+        input.end.i,
+        input.end.j,
+        Cell::new('E').fg(Color::Red).bold(),
+    );
+    frame
+}
+
+impl SharedSolution for Day16 {
+    type Input = Day16Input;
+    type Shared = Day16Shared;
+    type Output = usize;
+
+    fn parse_input(raw_input: String) -> Self::Input {
+        let mut start = None;
+        let mut end = None;
+        let mut walls = HashSet::new();
+
+        for (i, line) in raw_input.trim().lines().enumerate() {
+            for (j, ch) in line.chars().enumerate() {
+                let pos = Index2D::new(i, j);
+
+                match ch {
+                    '#' => {
+                        walls.insert(pos);
+                    }
+                    'S' => {
+                        start = Some(pos);
+                    }
+                    'E' => {
+                        end = Some(pos);
+                    }
+                    '.' => {}
+                    _ => panic!("invalid input character"),
+                }
+            }
+        }
+
+        Day16Input {
+            start: start.unwrap(),
+            end: end.unwrap(),
+            walls,
+        }
+    }
+
+    fn solve(input: &Self::Input) -> SolutionResult<Self::Shared> {
+        let scores = scores_from_start(input);
+        let best_score = best_score_of(input, &scores).ok_or(SolutionError::BadInput)?;
+
+        Ok(Day16Shared { best_score, scores })
+    }
+
+    fn part_1(_input: &Self::Input, shared: &Self::Shared) -> SolutionResult<Self::Output> {
+        Ok(shared.best_score)
+    }
+
+    fn part_2(input: &Self::Input, shared: &Self::Shared) -> SolutionResult<Self::Output> {
+        Ok(tiles_on_best_paths(
+            input,
+            &shared.scores,
+            shared.best_score,
+        ))
+    }
+
+    fn visualize(input: &Self::Input, animation: &mut Animation) -> SolutionResult<()> {
+        let height = input.walls.iter().map(|wall| wall.i).max().unwrap_or(0) + 1;
+        let width = input.walls.iter().map(|wall| wall.j).max().unwrap_or(0) + 1;
+        let open_cells = height * width - input.walls.len();
+        // Each tile is searched at most once per direction it can be faced in.
+        let stride = (4 * open_cells).div_ceil(MAX_FRAMES).max(1);
+
+        let mut explored = HashSet::new();
+        let mut steps = 0;
+
+        let start = (input.start, Direction::Right);
+        let next = |state| forward_moves(input, state);
+        let scores = state_scores_visiting(&[start], next, |(pos, _)| {
+            // A tile can be reached again facing another way, which leaves the number of
+            // explored tiles unchanged, so steps are counted separately.
+            explored.insert(pos);
+            steps += 1;
+
+            if steps % stride == 0 {
+                let caption = format!("{} tiles explored", explored.len());
+                animation.push(render_maze(input, &explored).with_caption(caption));
+            }
+        });
+
+        let caption = match best_score_of(input, &scores) {
+            Some(score) => format!("{} tiles explored, lowest score: {}", explored.len(), score),
+            None => format!("{} tiles explored, end unreachable", explored.len()),
+        };
+        animation.push(render_maze(input, &explored).with_caption(caption));
+
+        Ok(())
+    }
+
+    fn alternatives() -> Vec<Alternative<Self::Input, Self::Output>> {
+        vec![
+            Alternative::new("backwards", 1, |input| {
+                let to_end = scores_to_end(input);
+                let start = (input.start, Direction::Right);
+                to_end.get(&start).copied().ok_or(SolutionError::BadInput)
+            }),
+            Alternative::new("per state", 2, tiles_on_best_paths_per_state),
+        ]
+    }
+}
+
+impl InputGenerator for Day16 {
+    fn generate(rng: &mut Rng) -> Generated {
+        let (height, width) = (rng.usize(4..10), rng.usize(4..10));
+
+        let maze = (0..height)
+            .map(|i| {
+                let mut line = (0..width)
+                    .map(|j| match (i, j) {
+                        _ if i == 0 || j == 0 || i == height - 1 || j == width - 1 => '#',
+                        _ if (i, j) == (height - 2, 1) => 'S',
+                        _ if (i, j) == (1, width - 2) => 'E',
+                        _ if rng.u8(0..4) == 0 => '#',
+                        _ => '.',
+                    })
+                    .collect::<String>();
+                line.push('\n');
+                line
+            })
+            .collect::<String>();
+
+        Generated::new(maze)
+    }
+
+    /// Removing lines would open up the outer wall, past which the search never ends, so walls
+    /// inside the maze are taken down one at a time instead.
+    fn shrink(generated: &Generated) -> Vec<Generated> {
+        let lines = generated.input.lines().collect::<Vec<_>>();
+        let width = lines.first().map_or(0, |line| line.len());
+        let inner_walls = generated
+            .input
+            .match_indices('#')
+            .map(|(k, _)| k)
+            .filter(|k| {
+                let (i, j) = (k / (width + 1), k % (width + 1));
+                i > 0 && i + 1 < lines.len() && j > 0 && j + 1 < width
+            });
+
+        inner_walls
+            .map(|k| {
+                let mut input = generated.input.clone();
+                input.replace_range(k..=k, ".");
+                Generated {
+                    input,
+                    params: generated.params.clone(),
+                }
+            })
+            .collect()
+    }
+}
+
+pub(crate) fn solution() -> aoc_lib::cli::Solution {
+    aoc_lib::cli::Solution::new_shared_generated::<Day16>(16)
+}
@@ -1,6 +1,4 @@
-use std::fmt::Display;
-
-use aoc_lib::cli::{PuzzleSolution, SolutionResult};
+use aoc_lib::cli::{Answer, OwnedSolution, SolutionResult};
 
 #[derive(Debug, Clone)]
 struct Day17Input {
@@ -35,9 +33,8 @@ impl WeirdComputer {
         loop {
             let status = self.step();
 
-            match status {
-                StepStatus::Halt => break,
-                _ => {}
+            if status == StepStatus::Halt {
+                break;
             }
         }
     }
@@ -126,9 +123,9 @@ fn concat_octals(arr: &[u8]) -> u64 {
 
 struct Day17;
 
-impl PuzzleSolution for Day17 {
+impl OwnedSolution for Day17 {
     type Input = Day17Input;
-    type Output = Box<dyn Display>;
+    type Output = Answer;
 
     fn parse_input(raw_input: String) -> Self::Input {
         let lines = raw_input.lines().collect::<Vec<_>>();
@@ -155,16 +152,16 @@ impl PuzzleSolution for Day17 {
         }
     }
 
-    fn part_1(input: &Self::Input) -> SolutionResult<Self::Output> {
+    fn part_1(input: Self::Input) -> SolutionResult<Self::Output> {
         let mut computer = WeirdComputer::new();
         computer.a = input.reg_a;
         computer.b = input.reg_b;
         computer.c = input.reg_c;
-        computer.program = input.program.clone();
+        computer.program = input.program;
 
         computer.run();
 
-        Ok(Box::new(
+        Ok(Answer::from(
             computer
                 .output
                 .iter()
@@ -174,7 +171,7 @@ impl PuzzleSolution for Day17 {
         ))
     }
 
-    fn part_2(input: &Self::Input) -> SolutionResult<Self::Output> {
+    fn part_2(input: Self::Input) -> SolutionResult<Self::Output> {
         let mut digits = vec![0; input.program.len()];
         let mut i = 0;
 
@@ -209,10 +206,10 @@ impl PuzzleSolution for Day17 {
             digits[i] += 1;
         }
 
-        Ok(Box::new(concat_octals(&digits)))
+        Ok(Answer::from(concat_octals(&digits)))
     }
 }
 
-fn main() {
-    aoc_lib::cli::run_solution::<Day17>(2024, 17);
+pub(crate) fn solution() -> aoc_lib::cli::Solution {
+    aoc_lib::cli::Solution::new_owned::<Day17>(17)
 }
@@ -0,0 +1,231 @@
+use std::collections::HashSet;
+
+use aoc_lib::{
+    cli::{
+        Alternative, Answer, Generated, InputGenerator, Param, ParamInfo, PuzzleSolution, Rng,
+        SolutionError, SolutionResult,
+    },
+    helper::structs::{Direction, DIRECTIONS},
+};
+
+const GRID_SIZE: Param<usize> = Param::new("grid_size", 71);
+const FALLEN_BYTES: Param<usize> = Param::new("fallen_bytes", 1024);
+
+/// Checks whether the exit can be reached once the given bytes have fallen.
+fn is_reachable(walls: &[(usize, usize)], grid_size: usize) -> bool {
+    let walls = walls.iter().collect::<HashSet<_>>();
+    let mut stack = vec![(0, 0)];
+    let mut visited = HashSet::from([(0, 0)]);
+
+    while let Some(pos) = stack.pop() {
+        if pos == (grid_size - 1, grid_size - 1) {
+            return true;
+        }
+
+        let neighbors = [
+            (pos.0.wrapping_sub(1), pos.1),
+            (pos.0, pos.1 + 1),
+            (pos.0 + 1, pos.1),
+            (pos.0, pos.1.wrapping_sub(1)),
+        ];
+
+        for new_pos in neighbors {
+            if new_pos.0 < grid_size
+                && new_pos.1 < grid_size
+                && !walls.contains(&new_pos)
+                && visited.insert(new_pos)
+            {
+                stack.push(new_pos);
+            }
+        }
+    }
+
+    false
+}
+
+/// Finds the first byte that cuts off the exit by binary searching the number of fallen bytes,
+/// since the exit stays cut off once it is.
+fn first_blocking_byte(walls: &[(usize, usize)]) -> SolutionResult<Answer> {
+    let grid_size = GRID_SIZE.get();
+
+    if is_reachable(walls, grid_size) {
+        return Err(SolutionError::BadInput);
+    }
+
+    let (mut low, mut high) = (0, walls.len());
+
+    while low < high {
+        let mid = (low + high) / 2;
+
+        if is_reachable(&walls[..mid], grid_size) {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+
+    let (x, y) = walls[low - 1];
+    Ok(Answer::from(format!("{},{}", x, y)))
+}
+
+struct Day18;
+
+impl PuzzleSolution for Day18 {
+    type Input = Vec<(usize, usize)>;
+    type Output = Answer;
+
+    fn parse_input(raw_input: String) -> Self::Input {
+        raw_input
+            .trim()
+            .lines()
+            .map(|line| {
+                let (l, r) = line.split_once(",").unwrap();
+                (l.parse().unwrap(), r.parse().unwrap())
+            })
+            .collect()
+    }
+
+    fn part_1(walls: &Self::Input) -> SolutionResult<Self::Output> {
+        let grid_size = GRID_SIZE.get();
+        let walls = walls
+            .get(..FALLEN_BYTES.get())
+            .ok_or(SolutionError::BadInput)?;
+
+        let mut queue = vec![((0, 0), 0)];
+        let mut visited = HashSet::new();
+
+        while let Some((pos, distance)) = queue.pop() {
+            for direction in DIRECTIONS {
+                if pos == (grid_size - 1, grid_size - 1) {
+                    return Ok(Answer::from(distance));
+                }
+
+                let bound_check = match direction {
+                    Direction::Up => pos.0 > 0,
+                    Direction::Right => pos.1 < grid_size - 1,
+                    Direction::Down => pos.0 < grid_size - 1,
+                    Direction::Left => pos.1 > 0,
+                };
+
+                if !bound_check {
+                    continue;
+                }
+
+                let new_pos = match direction {
+                    Direction::Up => (pos.0 - 1, pos.1),
+                    Direction::Right => (pos.0, pos.1 + 1),
+                    Direction::Down => (pos.0 + 1, pos.1),
+                    Direction::Left => (pos.0, pos.1 - 1),
+                };
+
+                if !visited.contains(&new_pos) && !walls.contains(&new_pos) {
+                    visited.insert(new_pos);
+                    queue.insert(0, (new_pos, distance + 1));
+                }
+            }
+        }
+
+        panic!("End could not be reached");
+    }
+
+    // TODO: optimize using a `previous` field for each cell
+    fn part_2(walls: &Self::Input) -> SolutionResult<Self::Output> {
+        let grid_size = GRID_SIZE.get();
+        let mut last_history = vec![(0, 0)];
+
+        'main: for i in 1..=walls.len() {
+            let walls = &walls[..i];
+            let new_wall = walls.last().unwrap();
+
+            let mut end = last_history
+                .iter()
+                .position(|pos| pos == new_wall)
+                .unwrap_or(last_history.len());
+
+            while end > 0 {
+                let partial_history = &last_history[..end];
+                let mut queue = vec![Vec::from(partial_history)];
+                let mut visited: HashSet<_> = partial_history.iter().cloned().collect();
+
+                while let Some(history) = queue.pop() {
+                    for direction in DIRECTIONS {
+                        let pos = history.last().unwrap();
+
+                        if *pos == (grid_size - 1, grid_size - 1) {
+                            last_history = history;
+                            continue 'main;
+                        }
+
+                        let bound_check = match direction {
+                            Direction::Up => pos.0 > 0,
+                            Direction::Right => pos.1 < grid_size - 1,
+                            Direction::Down => pos.0 < grid_size - 1,
+                            Direction::Left => pos.1 > 0,
+                        };
+
+                        if !bound_check {
+                            continue;
+                        }
+
+                        let new_pos = match direction {
+                            Direction::Up => (pos.0 - 1, pos.1),
+                            Direction::Right => (pos.0, pos.1 + 1),
+                            Direction::Down => (pos.0 + 1, pos.1),
+                            Direction::Left => (pos.0, pos.1 - 1),
+                        };
+
+                        if !visited.contains(&new_pos) && !walls.contains(&new_pos) {
+                            visited.insert(new_pos);
+
+                            let mut new_history = history.clone();
+                            new_history.push(new_pos);
+                            queue.insert(0, new_history);
+                        }
+                    }
+                }
+
+                end -= 1;
+            }
+
+            return Ok(Answer::from(format!("{},{}", new_wall.0, new_wall.1)));
+        }
+
+        Err(SolutionError::BadInput)
+    }
+
+    fn params() -> Vec<ParamInfo> {
+        vec![GRID_SIZE.info(), FALLEN_BYTES.info()]
+    }
+
+    fn alternatives() -> Vec<Alternative<Self::Input, Self::Output>> {
+        vec![Alternative::new("binary search", 2, |walls| {
+            first_blocking_byte(walls)
+        })]
+    }
+}
+
+impl InputGenerator for Day18 {
+    fn generate(rng: &mut Rng) -> Generated {
+        let grid_size = rng.usize(2..10);
+
+        // Every cell but the start and the exit falls eventually, so the exit always gets cut off.
+        let mut bytes = (0..grid_size)
+            .flat_map(|x| (0..grid_size).map(move |y| (x, y)))
+            .filter(|&pos| pos != (0, 0) && pos != (grid_size - 1, grid_size - 1))
+            .collect::<Vec<_>>();
+        rng.shuffle(&mut bytes);
+
+        let input = bytes
+            .iter()
+            .map(|(x, y)| format!("{},{}\n", x, y))
+            .collect::<String>();
+
+        Generated::new(input)
+            .with_param("grid_size", grid_size)
+            .with_param("fallen_bytes", rng.usize(0..=bytes.len()))
+    }
+}
+
+pub(crate) fn solution() -> aoc_lib::cli::Solution {
+    aoc_lib::cli::Solution::new_generated::<Day18>(18)
+}
@@ -87,6 +87,6 @@ impl PuzzleSolution for Day19 {
     }
 }
 
-fn main() {
-    aoc_lib::cli::run_solution::<Day19>(2024, 19);
+pub(crate) fn solution() -> aoc_lib::cli::Solution {
+    aoc_lib::cli::Solution::new::<Day19>(19)
 }
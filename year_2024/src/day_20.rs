@@ -0,0 +1,153 @@
+use std::collections::HashMap;
+
+use aoc_lib::{
+    char_cell,
+    cli::{Param, ParamInfo, SharedSolution, SolutionResult},
+    helper::structs::{Direction, Grid, GridParser, Index2D, DIRECTIONS},
+};
+
+const MIN_SAVED_TIME: Param<usize> = Param::new("min_saved_time", 100);
+
+struct Day20;
+
+char_cell! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Tile {
+        Wall = '#',
+        Track = '.',
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Day20Input {
+    map: Grid<Tile>,
+    start: (usize, usize),
+    end: (usize, usize),
+}
+
+/// Returns the time at which each position of the track is reached.
+fn trace_track(input: &Day20Input) -> HashMap<(usize, usize), isize> {
+    let _t = aoc_lib::span("trace track");
+    let Day20Input { map, start, end } = input;
+    let grid_size = (map.height(), map.width());
+
+    let mut times = HashMap::new();
+    times.insert(*start, 0);
+
+    let mut pos = *start;
+
+    while pos != *end {
+        for direction in DIRECTIONS {
+            let bound_check = match direction {
+                Direction::Up => pos.0 > 0,
+                Direction::Right => pos.1 < grid_size.1 - 1,
+                Direction::Down => pos.0 < grid_size.0 - 1,
+                Direction::Left => pos.1 > 0,
+            };
+
+            if !bound_check {
+                continue;
+            }
+
+            let new_pos = match direction {
+                Direction::Up => (pos.0 - 1, pos.1),
+                Direction::Right => (pos.0, pos.1 + 1),
+                Direction::Down => (pos.0 + 1, pos.1),
+                Direction::Left => (pos.0, pos.1 - 1),
+            };
+
+            if !times.contains_key(&new_pos)
+                && map[Index2D::new(new_pos.0, new_pos.1)] != Tile::Wall
+            {
+                let current_time = times[&pos];
+                times.insert(new_pos, current_time + 1);
+                pos = new_pos;
+                break;
+            }
+        }
+    }
+
+    times
+}
+
+fn count_cheats(times: &HashMap<(usize, usize), isize>, max_cheat: isize) -> usize {
+    let _t = aoc_lib::span("count cheats");
+    let mut saves = HashMap::<usize, usize>::new();
+
+    for (cheat_start, &start_time) in times {
+        for i in -max_cheat..=max_cheat {
+            let j_span = max_cheat - i.abs();
+
+            for j in -j_span..=j_span {
+                if cheat_start.0 as isize + i < 0 || cheat_start.1 as isize + j < 0 {
+                    continue;
+                }
+
+                let cheat_end = (
+                    (cheat_start.0 as isize + i) as usize,
+                    (cheat_start.1 as isize + j) as usize,
+                );
+                let time_spent = i.abs() + j.abs();
+
+                if let Some(&end_time) = times.get(&(cheat_end)) {
+                    if end_time > start_time + time_spent {
+                        let saved_time = end_time - start_time - time_spent;
+                        let entry = saves.entry(saved_time as usize).or_default();
+                        *entry += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    let _t = aoc_lib::span("tally savings");
+    let min_saved_time = MIN_SAVED_TIME.get();
+    saves
+        .iter()
+        .filter(|(&time, _)| time >= min_saved_time)
+        .map(|(_, freq)| freq)
+        .sum()
+}
+
+impl SharedSolution for Day20 {
+    type Input = Day20Input;
+    type Shared = HashMap<(usize, usize), isize>;
+    type Output = usize;
+
+    fn parse_input(raw_input: String) -> Self::Input {
+        let parsed = GridParser::new()
+            .marker('S', Tile::Track)
+            .marker('E', Tile::Track)
+            .parse(&raw_input)
+            .unwrap_or_else(|e| panic!("invalid map: {}", e));
+
+        let start = parsed.marker('S').expect("the map has no start");
+        let end = parsed.marker('E').expect("the map has no end");
+
+        Day20Input {
+            map: parsed.grid,
+            start: (start.i, start.j),
+            end: (end.i, end.j),
+        }
+    }
+
+    fn solve(input: &Self::Input) -> SolutionResult<Self::Shared> {
+        Ok(trace_track(input))
+    }
+
+    fn part_1(_input: &Self::Input, times: &Self::Shared) -> SolutionResult<Self::Output> {
+        Ok(count_cheats(times, 2))
+    }
+
+    fn part_2(_input: &Self::Input, times: &Self::Shared) -> SolutionResult<Self::Output> {
+        Ok(count_cheats(times, 20))
+    }
+
+    fn params() -> Vec<ParamInfo> {
+        vec![MIN_SAVED_TIME.info()]
+    }
+}
+
+pub(crate) fn solution() -> aoc_lib::cli::Solution {
+    aoc_lib::cli::Solution::new_shared::<Day20>(20)
+}
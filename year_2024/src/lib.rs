@@ -0,0 +1,46 @@
+mod day_01;
+mod day_02;
+mod day_03;
+mod day_04;
+mod day_05;
+mod day_06;
+mod day_07;
+mod day_08;
+mod day_09;
+mod day_10;
+mod day_11;
+mod day_12;
+mod day_13;
+mod day_14;
+mod day_15;
+mod day_16;
+mod day_17;
+mod day_18;
+mod day_19;
+mod day_20;
+
+use aoc_lib::cli::Registry;
+
+pub fn registry() -> Registry {
+    Registry::new(2024)
+        .with_solution(day_01::solution())
+        .with_solution(day_02::solution())
+        .with_solution(day_03::solution())
+        .with_solution(day_04::solution())
+        .with_solution(day_05::solution())
+        .with_solution(day_06::solution())
+        .with_solution(day_07::solution())
+        .with_solution(day_08::solution())
+        .with_solution(day_09::solution())
+        .with_solution(day_10::solution())
+        .with_solution(day_11::solution())
+        .with_solution(day_12::solution())
+        .with_solution(day_13::solution())
+        .with_solution(day_14::solution())
+        .with_solution(day_15::solution())
+        .with_solution(day_16::solution())
+        .with_solution(day_17::solution())
+        .with_solution(day_18::solution())
+        .with_solution(day_19::solution())
+        .with_solution(day_20::solution())
+}
@@ -0,0 +1,31 @@
+use aoc_lib::cli::cross_check;
+
+/// Checks the alternatives of a day against its parts on generated inputs.
+fn check(day: u32) {
+    let registry = year_2024::registry();
+    let solution = registry.get(day).expect("day is not registered");
+
+    if let Some(mismatch) = cross_check(solution, 0..100) {
+        panic!("day {}: {}", day, mismatch);
+    }
+}
+
+#[test]
+fn day_07() {
+    check(7);
+}
+
+#[test]
+fn day_14() {
+    check(14);
+}
+
+#[test]
+fn day_16() {
+    check(16);
+}
+
+#[test]
+fn day_18() {
+    check(18);
+}
@@ -0,0 +1,107 @@
+use aoc_lib::cli::with_params;
+use std::fs;
+
+/// Solves a day against its example in `tests/examples`, checking the answers that are given and
+/// that the alternative implementations of each part agree with it.
+fn check(day: u32, params: &[(&str, &str)], expected: [Option<&str>; 2]) {
+    let registry = year_2024::registry();
+    let solution = registry.get(day).expect("day is not registered");
+
+    let path = format!(
+        "{}/tests/examples/day_{:02}.txt",
+        env!("CARGO_MANIFEST_DIR"),
+        day
+    );
+    let raw_input = fs::read_to_string(path).expect("could not read example");
+
+    with_params(params, || {
+        let input = solution.parse(raw_input);
+
+        for (part, expected) in (1..).zip(expected) {
+            if let Some(expected) = expected {
+                let answer = solution.part(&input, part).unwrap();
+                assert_eq!(answer.to_string(), expected, "day {} part {}", day, part);
+            }
+
+            for name in solution.alternatives(part) {
+                let answer = solution.alternative(&input, part, name).unwrap().unwrap();
+                let expected = solution.part(&input, part).unwrap();
+                assert_eq!(answer, expected, "day {} part {} ({})", day, part, name);
+            }
+        }
+    });
+}
+
+#[test]
+fn day_01() {
+    check(1, &[], [Some("11"), Some("31")]);
+}
+
+#[test]
+fn day_02() {
+    check(2, &[], [Some("2"), Some("4")]);
+}
+
+#[test]
+fn day_04() {
+    check(4, &[], [Some("18"), Some("9")]);
+}
+
+#[test]
+fn day_06() {
+    check(6, &[], [Some("41"), Some("6")]);
+}
+
+#[test]
+fn day_07() {
+    check(7, &[], [Some("3749"), Some("11387")]);
+}
+
+#[test]
+fn day_09() {
+    check(9, &[], [Some("1928"), Some("2858")]);
+}
+
+#[test]
+fn day_10() {
+    check(10, &[], [Some("36"), Some("81")]);
+}
+
+#[test]
+fn day_11() {
+    check(11, &[], [Some("55312"), None]);
+}
+
+#[test]
+fn day_14() {
+    check(14, &[("width", "11"), ("height", "7")], [Some("12"), None]);
+}
+
+#[test]
+fn day_16() {
+    check(16, &[], [Some("7036"), Some("45")]);
+}
+
+#[test]
+fn day_17() {
+    check(17, &[], [Some("4,6,3,5,6,3,5,2,1,0"), None]);
+}
+
+#[test]
+fn day_18() {
+    check(
+        18,
+        &[("grid_size", "7"), ("fallen_bytes", "12")],
+        [Some("22"), Some("6,1")],
+    );
+}
+
+#[test]
+fn day_19() {
+    check(19, &[], [Some("6"), Some("16")]);
+}
+
+#[test]
+fn day_20() {
+    check(20, &[("min_saved_time", "50")], [Some("1"), Some("285")]);
+}